@@ -5,10 +5,10 @@
 //! ## Motivation
 //! A derive macros to support a builder pattern for Rust:
 //! - Everything except `Option<>` fields and explicitly defined `default` attribute in structs are required, so you
-//! don't need any additional attributes to indicate it, and the presence of required params
-//! is checked at the compile time (not at the runtime).
+//!   don't need any additional attributes to indicate it, and the presence of required params
+//!   is checked at the compile time (not at the runtime).
 //! - To create new struct instances there is `::new` and an auxiliary init struct definition
-//! with only required fields (to compensate the Rust's named params inability).
+//!   with only required fields (to compensate the Rust's named params inability).
 //!
 //! ## Usage:
 //!
@@ -38,535 +38,3630 @@
 //!
 //! The macros generates the following functions and instances for your structures:
 //! - `with/without_<field_name>` : immutable setters for fields
-//! - `<field_name>/reset_<field_name>` : mutable setters for fields
+//! - `take_<field_name>` : moves a value out of an `Option<>` field leaving `None`, or out of a non-option
+//!   owned `String`/collection field leaving an empty value
+//! - `get_or_insert_<field_name>` : inserts a value into an empty `Option<>` field and returns a mutable reference to it
+//! - `with_<field_name>_if` : immutable setters applied only when a condition is `true`
+//! - `with_<field_name>_or` : immutable setters for `Option<>` fields applied only when the field is `None`
+//! - `with_<field_name>_opt` : immutable setters for non-option fields applied only when the value is `Some`
+//! - `with_<field_name>_from` : immutable setters accepting any value convertible with `Into<>` to the field type
+//! - `with_<field_name>_ref` : immutable setters for primitive scalar fields accepting a reference to the value
+//! - `<field_name>/reset_<field_name>` : mutable setters for fields (`reset` sets `None` for `Option<>` fields and
+//!   the default value for fields with a `default` attribute)
+//! - `with_<field_name>_millis/secs` : immutable setters for `Duration` fields accepting milliseconds/seconds
+//! - `try_with_<field_name>` : immutable fallible setter for `NonZero*` fields accepting a primitive value
+//!   (returning an error for zero) and for fields with a `try_from` attribute converting a source value with `TryFrom<>`
+//! - `set_<field_name>_at` : mutable setter for an element of array fields (panics when out of bounds)
+//! - `with_<field_name>_borrowed/owned` : immutable setters for `Cow<>` fields wrapping a borrowed/owned value
+//! - `with_<field_name>_path` : immutable setters for `PathBuf` fields accepting any `AsRef<Path>` value
 //! - `new` : factory method with required fields as arguments
+//! - `Default` instance with `#[builder(derive_default)]` on a struct
 //! - `From<>` instance from an an auxiliary init struct definition with only required fields.
-//! The init structure generated as `<YourStructureName>Init`. So, you can use `from(...)` or `into()`
-//! functions from it.
+//!   The init structure generated as `<YourStructureName>Init`. So, you can use `from(...)` or `into()`
+//!   functions from it.
+//! - `From<>` instance from a tuple of required fields when a struct has two or more of them,
+//!   or with `#[builder(from_field)]` on a struct from the required field value itself when there is only one
+//! - `build_with` : creates a struct instance from its init structure and applies a function to it
+//!   (not available for structs with validators)
+//! - `into_init` : converts a struct instance back to its init structure with only required fields
+//!   (also available as `From<>` instance for the init structure)
+//! - `apply` : with `#[builder(apply)]` on a struct, runs a function with mutable setters on an instance
+//!   inside an immutable setters chain
+//! - `field_names` : returns the names of all struct fields in the declaration order
+//! - `REQUIRED_FIELDS` : constant with the names of the fields required by `new`
+//! - `merge` : with `#[builder(merge)]` on a struct, combines two instances, `Some` values of `Option<>` fields
+//!   from the argument override the current ones and other fields are always taken from the argument
+//! - `with_defaults` : with `#[builder(with_defaults)]` on a struct, resets fields with defaults back to them
+//!   and `Option<>` fields to `None`, keeping required fields
 //!
-//! ## Defaults
+//! A method name generated for one field that matches a method of another field
+//! (like `with_timeout_secs` for `timeout: Duration` next to a `timeout_secs` field) is reported as a compile error.
+//!
+//! Doc comments of fields are copied to the corresponding `with_<field_name>` setters.
+//!
+//! ## Tuple structs
+//!
+//! Tuple structs get the `new` factory method and positional immutable setters
+//! (`with_<index>`, and `without/opt_<index>` for `Option<>` fields).
+//! `#[builder(...)]` attributes aren't supported on their fields:
 //!
 //! ```
 //! use rsb_derive::Builder;
 //!
 //! #[derive(Debug, Clone, PartialEq, Builder)]
-//! struct StructWithDefault {
+//! struct Wrapper(String, Option<i32>);
+//!
+//! let w = Wrapper::new("hey".into()).with_1(10);
+//! ```
+//!
+//! ## Into setters
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! struct StructWithInto {
+//!     #[builder(into)]
+//!     pub req_field1: String, // setters accept `impl Into<String>`, `new` keeps `String`
+//!     #[builder(into)]
+//!     pub opt_field1: Option<String>
+//! }
+//!
+//! let s = StructWithInto::new("hey".into()).with_req_field1("hey2").with_opt_field1("hey3");
+//! ```
+//!
+//! `#[builder(field_defaults(into))]` on a struct does the same for all fields,
+//! and `#[builder(into = false)]` opts a field out of it:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(field_defaults(into))]
+//! struct StructWithInto {
 //!     pub req_field1: String,
-//!     #[default="10"]
-//!     pub req_field2: i32, // default here make this field behave like optional
+//!     pub opt_field1: Option<String>,
+//!     #[builder(into = false)]
+//!     pub opt_field2: Option<i64>,
+//! }
+//!
+//! let s = StructWithInto::new("hey".into()).with_req_field1("hey2").with_opt_field1("hey3").with_opt_field2(1);
+//! ```
+//!
+//! ## Getters
+//!
+//! Fields marked with `#[builder(getter)]` get a public `get_<field_name>` getter,
+//! which is useful for private fields (`Option<>` fields are returned as `Option<&T>`):
+//!
+//! ```
+//! mod inner {
+//!     use rsb_derive::Builder;
+//!
+//!     #[derive(Debug, Clone, PartialEq, Builder)]
+//!     pub struct StructWithGetters {
+//!         #[builder(getter)]
+//!         req_field1: String,
+//!         #[builder(getter)]
+//!         opt_field1: Option<String>
+//!     }
+//! }
+//!
+//! let s = inner::StructWithGetters::new("hey".into());
+//! assert_eq!(s.get_req_field1(), "hey");
+//! assert_eq!(s.get_opt_field1(), None);
+//! ```
+//!
+//! ## Skipping fields
+//!
+//! Fields marked with `#[builder(skip)]` are excluded from `new` and the init struct
+//! and initialized with `Default::default()`:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! struct StructWithSkip {
+//!     pub req_field1: String,
+//!     #[builder(skip)]
+//!     pub computed_field1: Vec<String>,
+//! }
+//!
+//! assert!(StructWithSkip::new("hey".into()).computed_field1.is_empty());
+//! ```
+//!
+//! Fields marked with `#[builder(skip_setters)]` are still initialized by `new` and the init struct,
+//! but don't have any setters, so they can't be changed with the builder methods afterwards.
+//!
+//! ## Collection item setters
+//!
+//! `#[builder(each = "name")]` on a `Vec<>` or set field renames its immutable item setter
+//! from `with_<field_name>_item` to `with_<name>`:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! struct MyStructure {
+//!     #[builder(each = "tag")]
+//!     pub tags: Vec<String>,
+//! }
+//!
+//! assert_eq!(MyStructure::new(vec![]).with_tag("a".into()).tags, vec!["a".to_string()]);
+//! ```
+//!
+//! ## Flag setters
+//!
+//! `#[builder(setter(strip_bool))]` on a `bool` field makes `with_<field_name>` take no arguments
+//! and set the field to `true`, while the setter with a value is available as `with_<field_name>_value`:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! struct MyStructure {
+//!     #[default = "false"]
+//!     #[builder(setter(strip_bool))]
+//!     pub verbose: bool,
+//!     #[default = "false"]
+//!     #[builder(setter(strip_bool))]
+//!     pub dry_run: bool,
+//! }
+//!
+//! let s = MyStructure::new().with_verbose().with_dry_run_value(false);
+//! assert!(s.verbose && !s.dry_run);
+//! ```
+//!
+//! ## Renaming setters
+//!
+//! `#[builder(rename = "name")]` changes the base name of the generated methods for a field:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! struct StructWithRename {
+//!     #[builder(rename = "value")]
+//!     pub v: i32,
+//! }
+//!
+//! assert_eq!(StructWithRename::new(1).with_value(2).v, 2);
+//! ```
+//!
+//! ## Completeness check
+//!
+//! `#[builder(complete_when_all_set)]` on a struct generates `is_complete`,
+//! which returns `true` when all `Option<>` fields are set:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(complete_when_all_set)]
+//! struct MyStructure {
+//!     pub req_field1: String,
+//!     pub opt_field1: Option<i32>,
+//! }
+//!
+//! assert!(!MyStructure::new("hey".into()).is_complete());
+//! assert!(MyStructure::new("hey".into()).with_opt_field1(1).is_complete());
+//! ```
+//!
+//! ## Changed fields
+//!
+//! `#[builder(diff)]` on a struct generates `changed_fields`, which returns the names
+//! of the fields that differ between two instances (all field types have to be `PartialEq`):
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(diff)]
+//! struct MyStructure {
+//!     pub req_field1: String,
+//!     pub opt_field1: Option<i32>,
+//! }
+//!
+//! let s1 = MyStructure::new("hey".into());
+//! assert_eq!(s1.changed_fields(&s1.clone().with_opt_field1(1)), vec!["opt_field1"]);
+//! ```
+//!
+//! ## Mutable setters prefix
+//!
+//! `#[builder(setter_prefix = "set_")]` on a struct adds a prefix to the `<field_name>` mutable setters:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(setter_prefix = "set_")]
+//! struct MyStructure {
+//!     pub req_field1: String,
+//!     pub opt_field1: Option<i32>,
+//! }
+//!
+//! let mut s = MyStructure::new("hey".into());
+//! s.set_req_field1("hey2".into()).set_opt_field1(1);
+//! ```
+//!
+//! ## Setters pattern
+//!
+//! `#[builder(pattern = "owned")]` on a struct generates only the immutable setters
+//! (like `with/without_<field_name>`) consuming and returning `Self`,
+//! while `#[builder(pattern = "mutable")]` generates only the mutable setters working with `&mut self`
+//! (both are generated by default):
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(pattern = "mutable")]
+//! struct MyStructure {
+//!     pub req_field1: String,
+//!     pub opt_field1: Option<i32>,
+//! }
+//!
+//! let mut s = MyStructure::new("hey".into());
+//! s.req_field1("hey2".into()).opt_field1(1);
+//! ```
 //!
+//! ## Option setters naming
+//!
+//! `#[builder(option_naming = "explicit")]` on a struct renames the `Option<>` field setters
+//! `reset_<field_name>`, `opt_<field_name>` and `mopt_<field_name>`
+//! to `clear_<field_name>`, `maybe_<field_name>` and `set_maybe_<field_name>`
+//! (it can be combined with `setter_prefix = "set_"`):
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(option_naming = "explicit", setter_prefix = "set_")]
+//! struct MyStructure {
+//!     pub req_field1: String,
+//!     pub opt_field1: Option<i32>,
+//! }
+//!
+//! let mut s = MyStructure::new("hey".into()).maybe_opt_field1(Some(1));
+//! s.set_opt_field1(2).clear_opt_field1().set_maybe_opt_field1(Some(3));
+//! ```
+//!
+//! ## Doc examples
+//!
+//! `#[builder(doc_examples)]` on a struct adds a short usage example
+//! (like `builder.with_<field_name>(value)`) to the docs of the generated setters.
+//!
+//! ## Mutable `with` setters
+//!
+//! `#[builder(mutable_with)]` on a struct makes `with_<field_name>` setters work with `&mut self`,
+//! so they can be chained with other mutable setters:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(mutable_with)]
+//! struct MyStructure {
+//!     pub req_field1: String,
+//!     pub opt_field1: Option<i32>,
+//! }
+//!
+//! let mut s = MyStructure::new("hey".into());
+//! s.with_req_field1("hey2".into()).with_opt_field1(1).reset_opt_field1();
+//! ```
+//!
+//! ## Cloning `with` setters
+//!
+//! `#[builder(clone_setters)]` on a `Clone` struct makes `with_<field_name>` setters work with `&self`
+//! and return an updated clone, so one base instance can be reused for several variants:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(clone_setters)]
+//! struct MyStructure {
+//!     pub req_field1: String,
+//!     pub opt_field1: Option<i32>,
+//! }
+//!
+//! let base = MyStructure::new("hey".into());
+//! let s1 = base.with_opt_field1(1);
+//! let s2 = base.with_opt_field1(2);
+//! assert_eq!(base.opt_field1, None);
+//! ```
+//!
+//! ## Tracing mutable setters
+//!
+//! `#[builder(trace)]` on a struct makes every mutable setter log the field name and its new value
+//! with `log::debug!`, so the crate needs the `log` dependency and the field types need `Debug`:
+//!
+//! ```ignore
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(trace)]
+//! struct MyStructure {
+//!     pub req_field1: String,
+//!     pub opt_field1: Option<i32>,
+//! }
+//!
+//! let mut s = MyStructure::new("hey".into());
+//! s.opt_field1(1); // logs `opt_field1 = Some(1)`
+//! ```
+//!
+//! ## Required optional fields
+//!
+//! `Option<>` fields marked with `#[builder(required)]` become arguments of `new` and the init struct
+//! (without `Option<>`), while keeping the `Option<>` setters
+//! (`into_init` and `From<>` for the init struct aren't generated for such structs):
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! struct StructWithRequiredOption {
+//!     #[builder(required)]
 //!     pub opt_field1: Option<String>,
-//!     #[default="Some(11)"]
-//!     pub opt_field2: Option<i32> // default works also on optional fields
 //! }
+//!
+//! assert_eq!(StructWithRequiredOption::new("hey".into()).opt_field1, Some("hey".into()));
 //! ```
 //!
-//! Details and source code: [https://github.com/abdolence/rust-struct-builder]: https://github.com/abdolence/rust-struct-builder
+//! ## Validation
 //!
+//! Required fields marked with `#[builder(validate = "fn_path")]` are checked by an additional
+//! `try_new` factory method, which returns the first validation error.
+//! For such structs the init struct is converted with `TryFrom<>` instead of `From<>`,
+//! and `validate_all` checks the current field values collecting all errors:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! fn non_empty(value: &str) -> Result<(), String> {
+//!     if value.is_empty() {
+//!         Err("value must not be empty".into())
+//!     } else {
+//!         Ok(())
+//!     }
+//! }
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! struct StructWithValidation {
+//!     #[builder(validate = "non_empty")]
+//!     pub req_field1: String,
+//!     pub opt_field1: Option<String>
+//! }
+//!
+//! assert!(StructWithValidation::try_new("".into()).is_err());
+//! assert!(StructWithValidation::try_from(StructWithValidationInit { req_field1: "".into() }).is_err());
+//! assert_eq!(
+//!     StructWithValidation::new("".into()).validate_all(),
+//!     Err(vec!["value must not be empty".to_string()])
+//! );
+//! ```
+//!
+//! ## Fallible setters
+//!
+//! Fields marked with `#[builder(try_from = "SourceType")]` get an additional `try_with_<field_name>` setter,
+//! which converts a source value with `TryFrom<>` and returns the conversion error if it fails:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! struct Email(String);
+//!
+//! impl TryFrom<String> for Email {
+//!     type Error = String;
+//!
+//!     fn try_from(value: String) -> Result<Self, Self::Error> {
+//!         if value.contains('@') {
+//!             Ok(Email(value))
+//!         } else {
+//!             Err(format!("invalid email: {}", value))
+//!         }
+//!     }
+//! }
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! struct MyStructure {
+//!     #[builder(try_from = "String")]
+//!     pub email: Option<Email>,
+//! }
+//!
+//! assert!(MyStructure::new().try_with_email("user@example.com".into()).is_ok());
+//! assert!(MyStructure::new().try_with_email("user".into()).is_err());
+//! ```
+//!
+//! ## Init struct name
+//!
+//! `#[builder(init_name = "Name")]` on a struct changes the name of the generated init struct:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(init_name = "MyStructureArgs")]
+//! struct MyStructure {
+//!     pub req_field1: String,
+//! }
+//!
+//! let s: MyStructure = MyStructureArgs { req_field1: "hey".into() }.into();
+//! ```
+//!
+//! ## Init struct derives
+//!
+//! `#[builder(init_derive(...))]` on a struct adds derives to the generated init struct.
+//! When they include `Serialize` or `Deserialize`, the `#[serde(...)]` field attributes
+//! are copied to the init struct fields as well:
+//!
+//! ```ignore
+//! #[derive(Debug, Clone, PartialEq, Builder, Serialize, Deserialize)]
+//! #[builder(init_derive(Debug, Deserialize))]
+//! struct MyStructure {
+//!     #[serde(rename = "name")]
+//!     pub req_field1: String,
+//!     pub opt_field1: Option<i32>,
+//! }
+//!
+//! let init: MyStructureInit = serde_json::from_str(r#"{ "name": "hey" }"#)?;
+//! ```
+//!
+//! The init struct of a `#[non_exhaustive]` struct is marked `#[non_exhaustive]` too,
+//! so it can't be constructed outside of the defining crate either.
+//!
+//! ## Visibility
+//!
+//! Generated methods and structs are `pub` (setters follow the field visibility) unless
+//! `#[builder(vis = "...")]` on a struct sets the visibility for all of them:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(vis = "pub(crate)")]
+//! pub struct MyStructure {
+//!     pub req_field1: String,
+//! }
+//!
+//! let s = MyStructure::new("hey".into()).with_req_field1("hey2".into());
+//! ```
+//!
+//! ## Factory method name
+//!
+//! `#[builder(name = "...")]` on a struct changes the name of the generated `new` factory method:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(name = "create")]
+//! struct MyStructure {
+//!     pub req_field1: String,
+//! }
+//!
+//! let s = MyStructure::create("hey".into());
+//! ```
+//!
+//! ## Inlining
+//!
+//! Generated setters are marked with `#[inline]`, which can be disabled with
+//! `#[builder(no_inline)]` on a struct to reduce the generated code size for large structs.
+//!
+//! ## Const factory method
+//!
+//! `#[builder(const_new)]` on a struct makes `new` a `const fn`
+//! (not supported for structs with `default` or skipped fields):
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(const_new)]
+//! struct MyStructure {
+//!     pub req_field1: i32,
+//!     pub opt_field1: Option<i32>,
+//! }
+//!
+//! const S: MyStructure = MyStructure::new(10);
+//! ```
+//!
+//! ## Staged builder
+//!
+//! `#[builder(staged)]` on a struct additionally generates a separate `<YourStructureName>Builder`
+//! with `with_<field_name>` setters and a `build()` method returning an error listing
+//! all missing required fields (or the first validator error, the same as `try_new`):
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(staged)]
+//! struct StructWithStagedBuilder {
+//!     pub req_field1: String,
+//!     pub req_field2: i32,
+//!     pub opt_field1: Option<String>
+//! }
+//!
+//! let s = StructWithStagedBuilder::builder()
+//!     .with_req_field1("hey".into())
+//!     .with_opt_field1("hey2".into())
+//!     .build();
+//!
+//! assert_eq!(s, Err("missing required fields: req_field2".into()));
+//! ```
+//!
+//! ## Typestate builder
+//!
+//! `#[builder(typestate)]` on a struct additionally generates a separate `<YourStructureName>TypestateBuilder`
+//! created with `typestate_builder()`, which tracks the required fields in its type,
+//! so `build()` is available only when all of them are set
+//! (it returns a `Result<>` with the first validator error when any required field has a validator):
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(typestate)]
+//! struct StructWithTypestate {
+//!     pub req_field1: String,
+//!     pub req_field2: i32,
+//!     pub opt_field1: Option<String>
+//! }
+//!
+//! let s = StructWithTypestate::typestate_builder()
+//!     .with_req_field1("hey".into())
+//!     .with_opt_field1("hey2".into())
+//!     .with_req_field2(1)
+//!     .build();
+//!
+//! assert_eq!(s, StructWithTypestate::new("hey".into(), 1).with_opt_field1("hey2".into()));
+//! ```
+//!
+//! ```compile_fail
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(typestate)]
+//! struct StructWithTypestate {
+//!     pub req_field1: String,
+//!     pub req_field2: i32,
+//! }
+//!
+//! let s = StructWithTypestate::typestate_builder()
+//!     .with_req_field1("hey".into())
+//!     .build();
+//! ```
+//!
+//! ## Defaults
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! struct StructWithDefault {
+//!     pub req_field1: String,
+//!     #[default="10"]
+//!     pub req_field2: i32, // default here make this field behave like optional
+//!
+//!     pub opt_field1: Option<String>,
+//!     #[default="Some(11)"]
+//!     pub opt_field2: Option<i32>, // default works also on optional fields
+//!     #[default]
+//!     pub req_field3: String, // bare default uses Default::default()
+//!     #[builder(default_fn = "default_timeout")]
+//!     pub req_field4: u64, // default computed by a function
+//!     #[builder(default_const = "DEFAULT_RETRIES")]
+//!     pub req_field5: u32, // default from an associated const
+//!     #[builder(default_try = "\"127.0.0.1\".parse()")]
+//!     pub req_field6: std::net::IpAddr // default from a Result, panics on errors
+//! }
+//!
+//! fn default_timeout() -> u64 {
+//!     30
+//! }
+//!
+//! impl StructWithDefault {
+//!     const DEFAULT_RETRIES: u32 = 3;
+//! }
+//! ```
+//!
+//! The `Default` instance is generated only with `#[builder(derive_default)]` on a struct,
+//! so it doesn't conflict with `#[derive(Default)]`, using defaults and `None` for other fields
+//! and `Default::default()` for required fields:
+//!
+//! ```
+//! use rsb_derive::Builder;
+//!
+//! #[derive(Debug, Clone, PartialEq, Builder)]
+//! #[builder(derive_default)]
+//! struct StructWithDerivedDefault {
+//!     pub req_field1: String,
+//!     pub opt_field1: Option<String>,
+//! }
+//!
+//! assert_eq!(StructWithDerivedDefault::default(), StructWithDerivedDefault::new("".into()));
+//! ```
+//!
+//! Details and source code: [https://github.com/abdolence/rust-struct-builder]: https://github.com/abdolence/rust-struct-builder
+//!
+
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::*;
+use syn::*;
+
+mod typestate;
+
+#[proc_macro_derive(Builder, attributes(default, builder))]
+pub fn struct_builder_macro(input: TokenStream) -> TokenStream {
+    let item: syn::Item = syn::parse(input).expect("failed to parse input");
+    let span = Span::call_site();
+    match item {
+        Item::Struct(ref struct_item) => {
+            let struct_name = &struct_item.ident;
+            let struct_attrs = match parse_struct_builder_attrs(&struct_item.attrs) {
+                Ok(attrs) => attrs,
+                Err(err) => return err.to_compile_error().into(),
+            };
+            let (struct_impl_generics, struct_ty_generics, struct_where_clause) =
+                struct_item.generics.split_for_impl();
+
+            let struct_decl: proc_macro2::TokenStream = quote! {
+                impl #struct_impl_generics #struct_name #struct_ty_generics #struct_where_clause
+            };
+
+            match struct_item.fields {
+                Fields::Named(ref named_fields) => {
+                    let mut struct_fields = match parse_fields(named_fields) {
+                        Ok(fields) => fields,
+                        Err(err) => return err.to_compile_error().into(),
+                    };
+
+                    if let Some(ref vis) = struct_attrs.vis {
+                        for field in struct_fields.iter_mut() {
+                            field.visibility = vis.clone();
+                        }
+                    }
+
+                    if struct_attrs.into {
+                        for field in struct_fields.iter_mut() {
+                            field.builder_attrs.into.get_or_insert(true);
+                        }
+                    }
+
+                    if struct_attrs.const_new {
+                        if let Some(field) = struct_fields
+                            .iter()
+                            .find(|f| f.default_tokens.is_some() || f.builder_attrs.skip)
+                        {
+                            return Error::new_spanned(
+                                &field.ident,
+                                "builder(const_new) can't be used with default or skipped fields",
+                            )
+                            .to_compile_error()
+                            .into();
+                        }
+                    }
+
+                    let init_struct_name = struct_attrs.init_struct_name(struct_name);
+                    let methods_visibility = struct_attrs.methods_visibility();
+
+                    let generated_factory_method =
+                        generate_factory_method(&struct_fields, &struct_attrs);
+                    let generated_into_init_method = generate_into_init_method(
+                        &init_struct_name,
+                        &struct_fields,
+                        &struct_item.generics,
+                        &methods_visibility,
+                    );
+                    let generated_build_with_method = generate_build_with_method(
+                        &init_struct_name,
+                        &struct_fields,
+                        &struct_item.generics,
+                        &methods_visibility,
+                    );
+                    let generated_merge_method = if struct_attrs.merge {
+                        generate_merge_method(&struct_fields, &methods_visibility)
+                    } else {
+                        quote! {}
+                    };
+                    let generated_reset_all_method = if struct_attrs.with_defaults {
+                        generate_reset_all_method(&struct_fields, &methods_visibility)
+                    } else {
+                        quote! {}
+                    };
+                    let generated_validate_all_method =
+                        generate_validate_all_method(&struct_fields, &methods_visibility);
+                    let generated_apply_method = if struct_attrs.apply {
+                        generate_apply_method(&struct_attrs)
+                    } else {
+                        quote! {}
+                    };
+                    let generated_field_names_method =
+                        generate_field_names_method(&struct_fields, &methods_visibility);
+                    let generated_is_complete_method = if struct_attrs.complete_when_all_set {
+                        generate_is_complete_method(&struct_fields, &methods_visibility)
+                    } else {
+                        quote! {}
+                    };
+                    let generated_changed_fields_method = if struct_attrs.diff {
+                        generate_changed_fields_method(&struct_fields, &methods_visibility)
+                    } else {
+                        quote! {}
+                    };
+                    let generated_fields_methods =
+                        generate_fields_functions(&struct_fields, &struct_attrs);
+
+                    let struct_methods = [
+                        &generated_factory_method,
+                        &generated_into_init_method,
+                        &generated_build_with_method,
+                        &generated_merge_method,
+                        &generated_reset_all_method,
+                        &generated_validate_all_method,
+                        &generated_apply_method,
+                        &generated_field_names_method,
+                        &generated_is_complete_method,
+                        &generated_changed_fields_method,
+                    ];
+                    if let Err(err) = check_generated_method_names(
+                        struct_methods
+                            .into_iter()
+                            .map(|methods| (None, methods))
+                            .chain(
+                                struct_fields
+                                    .iter()
+                                    .map(|f| Some(&f.ident))
+                                    .zip(generated_fields_methods.iter()),
+                            ),
+                    ) {
+                        return err.to_compile_error().into();
+                    }
+
+                    let generated_default_impl = generate_default_impl(
+                        struct_name,
+                        &struct_fields,
+                        &struct_item.generics,
+                        &struct_attrs,
+                    );
+
+                    let generated_aux_init_struct = generate_init_struct(
+                        struct_name,
+                        &init_struct_name,
+                        &struct_fields,
+                        &struct_item.generics,
+                        &struct_attrs,
+                    );
+
+                    let generated_tuple_from_impl = generate_tuple_from_impl(
+                        struct_name,
+                        &struct_fields,
+                        &struct_item.generics,
+                        &struct_attrs,
+                    );
+
+                    let generated_staged_builder = if struct_attrs.staged {
+                        generate_staged_builder(
+                            struct_name,
+                            &struct_fields,
+                            &struct_item.generics,
+                            &struct_attrs,
+                        )
+                    } else {
+                        quote! {}
+                    };
+
+                    let generated_typestate_builder = if struct_attrs.typestate {
+                        typestate::generate_typestate_builder(
+                            struct_name,
+                            &struct_fields,
+                            &struct_item.generics,
+                            &struct_attrs,
+                        )
+                    } else {
+                        quote! {}
+                    };
+
+                    let output = quote! {
+                        #[allow(dead_code)]
+                        #[allow(clippy::needless_update)]
+                        #struct_decl {
+                            #generated_factory_method
+                            #generated_into_init_method
+                            #generated_build_with_method
+                            #generated_merge_method
+                            #generated_reset_all_method
+                            #generated_validate_all_method
+                            #generated_apply_method
+                            #generated_field_names_method
+                            #generated_is_complete_method
+                            #generated_changed_fields_method
+                            #(#generated_fields_methods)*
+                        }
+
+                        #generated_aux_init_struct
+
+                        #generated_default_impl
+
+                        #generated_tuple_from_impl
+
+                        #generated_staged_builder
+
+                        #generated_typestate_builder
+                    };
+
+                    output.into()
+                }
+                Fields::Unnamed(ref unnamed_fields) => {
+                    let generated_tuple_methods =
+                        match generate_tuple_struct_functions(unnamed_fields, &struct_attrs) {
+                            Ok(methods) => methods,
+                            Err(err) => return err.to_compile_error().into(),
+                        };
+
+                    let output = quote! {
+                        #[allow(dead_code)]
+                        #[allow(clippy::needless_update)]
+                        #struct_decl {
+                            #generated_tuple_methods
+                        }
+                    };
+
+                    output.into()
+                }
+                Fields::Unit => Error::new(span, "Builder works only on the structs with fields")
+                    .to_compile_error()
+                    .into(),
+            }
+        }
+        _ => Error::new(span, "Builder derive works only on structs")
+            .to_compile_error()
+            .into(),
+    }
+}
+
+#[allow(clippy::enum_variant_names)]
+#[derive(Clone)]
+enum ParsedType {
+    StringType,
+    ScalarType,
+    OptionalType(Box<ParsedFieldType>),
+    VecType(Box<ParsedFieldType>),
+    SetType(Box<ParsedFieldType>),
+    MapType(Box<ParsedFieldType>, Box<ParsedFieldType>),
+    PointerType(PointerKind, Box<ParsedFieldType>),
+    NonZeroType(Box<ParsedFieldType>),
+    DurationType,
+    ArrayType(Box<ParsedFieldType>),
+    CowType(Lifetime, Box<ParsedFieldType>),
+    PathBufType,
+}
+
+impl ParsedType {
+    fn is_option(&self) -> bool {
+        matches!(self, ParsedType::OptionalType(_))
+    }
+}
+
+#[derive(Clone, Copy)]
+enum PointerKind {
+    Box,
+    Arc,
+    Rc,
+}
+
+impl PointerKind {
+    fn wrap_value(&self, value: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            PointerKind::Box => quote! { Box::new(#value) },
+            PointerKind::Arc => quote! { std::sync::Arc::new(#value) },
+            PointerKind::Rc => quote! { std::rc::Rc::new(#value) },
+        }
+    }
+
+    fn setter_suffix(&self) -> &'static str {
+        match self {
+            PointerKind::Box => "boxed",
+            PointerKind::Arc => "arc",
+            PointerKind::Rc => "rc",
+        }
+    }
+}
+
+#[derive(Clone)]
+struct ParsedFieldType {
+    field_type: Type,
+    parsed_type: Option<ParsedType>,
+    lifetime: Option<Lifetime>,
+}
+
+#[derive(Clone, Default)]
+struct ParsedStructBuilderAttrs {
+    merge: bool,
+    apply: bool,
+    from_field: bool,
+    with_defaults: bool,
+    staged: bool,
+    typestate: bool,
+    init_name: Option<Ident>,
+    vis: Option<Visibility>,
+    const_new: bool,
+    complete_when_all_set: bool,
+    setter_prefix: Option<String>,
+    name: Option<Ident>,
+    no_inline: bool,
+    mutable_with: bool,
+    clone_setters: bool,
+    trace: bool,
+    diff: bool,
+    explicit_option_naming: bool,
+    doc_examples: bool,
+    setters_pattern: SettersPattern,
+    init_derive: Vec<Path>,
+    non_exhaustive: bool,
+    into: bool,
+    derive_default: bool,
+}
+
+#[derive(Clone, Copy, Default, PartialEq)]
+enum SettersPattern {
+    #[default]
+    Both,
+    Owned,
+    Mutable,
+}
+
+impl ParsedStructBuilderAttrs {
+    fn init_struct_name(&self, struct_name: &Ident) -> Ident {
+        self.init_name
+            .clone()
+            .unwrap_or_else(|| format_ident!("{}Init", struct_name))
+    }
+
+    fn methods_visibility(&self) -> Visibility {
+        self.vis.clone().unwrap_or_else(|| parse_quote! { pub })
+    }
+
+    fn inline_attr(&self) -> proc_macro2::TokenStream {
+        if self.no_inline {
+            quote! {}
+        } else {
+            quote! { #[inline] }
+        }
+    }
+
+    fn factory_method_name(&self) -> Ident {
+        self.name.clone().unwrap_or_else(|| format_ident!("new"))
+    }
+
+    fn option_setter_name(
+        &self,
+        default_prefix: &str,
+        explicit_prefix: &str,
+        setter_name: &Ident,
+    ) -> Ident {
+        if self.explicit_option_naming {
+            format_ident!("{}{}", explicit_prefix, setter_name)
+        } else {
+            format_ident!("{}{}", default_prefix, setter_name)
+        }
+    }
+
+    fn setters_by_pattern(
+        &self,
+        mutable_setters: proc_macro2::TokenStream,
+        owned_setters: proc_macro2::TokenStream,
+    ) -> proc_macro2::TokenStream {
+        match self.setters_pattern {
+            SettersPattern::Both => quote! {
+                #mutable_setters
+                #owned_setters
+            },
+            SettersPattern::Owned => owned_setters,
+            SettersPattern::Mutable => mutable_setters,
+        }
+    }
+
+    fn init_derives_serde(&self) -> bool {
+        self.init_derive.iter().any(|path| {
+            path.segments
+                .last()
+                .is_some_and(|s| s.ident == "Serialize" || s.ident == "Deserialize")
+        })
+    }
+
+    fn mutable_setter_name(&self, setter_name: &Ident) -> Ident {
+        match self.setter_prefix {
+            Some(ref setter_prefix) => format_ident!("{}{}", setter_prefix, setter_name),
+            None => setter_name.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct ParsedFieldBuilderAttrs {
+    into: Option<bool>,
+    validate: Option<Path>,
+    getter: bool,
+    rename: Option<Ident>,
+    skip: bool,
+    default_fn: Option<Path>,
+    default_try: Option<Expr>,
+    skip_setters: bool,
+    strip_bool: bool,
+    try_from: Option<Type>,
+    default_const: Option<Ident>,
+    required: bool,
+    each: Option<Ident>,
+}
+
+#[derive(Clone)]
+struct ParsedField {
+    ident: Ident,
+    parsed_field_type: ParsedFieldType,
+    default_tokens: Option<proc_macro2::TokenStream>,
+    visibility: Visibility,
+    builder_attrs: ParsedFieldBuilderAttrs,
+    doc_attrs: Vec<Attribute>,
+    serde_attrs: Vec<Attribute>,
+}
+
+impl ParsedField {
+    fn is_option(&self) -> bool {
+        self.parsed_field_type
+            .parsed_type
+            .as_ref()
+            .filter(|t| t.is_option())
+            .is_some()
+    }
+
+    fn is_required_field(&self) -> bool {
+        (!self.is_option() || self.builder_attrs.required)
+            && self.default_tokens.is_none()
+            && !self.builder_attrs.skip
+    }
+
+    fn required_param_type(&self) -> &Type {
+        match self.parsed_field_type.parsed_type.as_ref() {
+            Some(ParsedType::OptionalType(ga_type_box)) if self.builder_attrs.required => {
+                &ga_type_box.field_type
+            }
+            _ => &self.parsed_field_type.field_type,
+        }
+    }
+
+    fn item_setter_name(&self) -> Ident {
+        match self.builder_attrs.each {
+            Some(ref each) => format_ident!("with_{}", each),
+            None => format_ident!("with_{}_item", self.setter_name()),
+        }
+    }
+
+    fn setter_name(&self) -> &Ident {
+        self.builder_attrs.rename.as_ref().unwrap_or(&self.ident)
+    }
+}
+
+#[inline]
+fn parse_field_type(field_type: &Type) -> ParsedFieldType {
+    match field_type {
+        Type::Path(ref path) => {
+            let full_type_path: &String = &path
+                .path
+                .segments
+                .iter()
+                .map(|s| s.ident.to_string())
+                .collect::<Vec<String>>()
+                .join("::");
+
+            let type_args: Vec<&Type> = match path.path.segments.last().unwrap().arguments {
+                PathArguments::AngleBracketed(ref params) => params
+                    .args
+                    .iter()
+                    .filter_map(|ga| match ga {
+                        GenericArgument::Type(ref ty) => Some(ty),
+                        _ => None,
+                    })
+                    .collect(),
+                _ => vec![],
+            };
+
+            let type_lifetime: Option<&Lifetime> =
+                match path.path.segments.last().unwrap().arguments {
+                    PathArguments::AngleBracketed(ref params) => {
+                        params.args.iter().find_map(|ga| match ga {
+                            GenericArgument::Lifetime(ref lt) => Some(lt),
+                            _ => None,
+                        })
+                    }
+                    _ => None,
+                };
+
+            let parsed_type = match full_type_path.as_str() {
+                "String" | "std::string::String" | "alloc::string::String" => {
+                    Some(ParsedType::StringType)
+                }
+                "Option" | "std::option::Option" | "core::option::Option" => type_args
+                    .first()
+                    .map(|ty| ParsedType::OptionalType(Box::from(parse_field_type(ty)))),
+                "Vec" | "std::vec::Vec" | "alloc::vec::Vec" => type_args
+                    .first()
+                    .map(|ty| ParsedType::VecType(Box::from(parse_field_type(ty)))),
+                "Box" | "std::boxed::Box" | "alloc::boxed::Box" => {
+                    type_args.first().filter(|ty| is_sized_type(ty)).map(|ty| {
+                        ParsedType::PointerType(PointerKind::Box, Box::from(parse_field_type(ty)))
+                    })
+                }
+                "Arc" | "std::sync::Arc" | "alloc::sync::Arc" => {
+                    type_args.first().filter(|ty| is_sized_type(ty)).map(|ty| {
+                        ParsedType::PointerType(PointerKind::Arc, Box::from(parse_field_type(ty)))
+                    })
+                }
+                "Rc" | "std::rc::Rc" | "alloc::rc::Rc" => {
+                    type_args.first().filter(|ty| is_sized_type(ty)).map(|ty| {
+                        ParsedType::PointerType(PointerKind::Rc, Box::from(parse_field_type(ty)))
+                    })
+                }
+                "HashSet"
+                | "std::collections::HashSet"
+                | "std::collections::hash_set::HashSet"
+                | "BTreeSet"
+                | "std::collections::BTreeSet"
+                | "std::collections::btree_set::BTreeSet"
+                | "alloc::collections::BTreeSet"
+                | "alloc::collections::btree_set::BTreeSet" => type_args
+                    .first()
+                    .map(|ty| ParsedType::SetType(Box::from(parse_field_type(ty)))),
+                "HashMap"
+                | "std::collections::HashMap"
+                | "std::collections::hash_map::HashMap"
+                | "BTreeMap"
+                | "std::collections::BTreeMap"
+                | "std::collections::btree_map::BTreeMap"
+                | "alloc::collections::BTreeMap"
+                | "alloc::collections::btree_map::BTreeMap" => match type_args.as_slice() {
+                    [key_type, value_type, ..] => Some(ParsedType::MapType(
+                        Box::from(parse_field_type(key_type)),
+                        Box::from(parse_field_type(value_type)),
+                    )),
+                    _ => None,
+                },
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
+                | "u128" | "usize" | "f32" | "f64" | "bool" | "char" => {
+                    Some(ParsedType::ScalarType)
+                }
+                "Cow" | "std::borrow::Cow" | "alloc::borrow::Cow" => {
+                    match (type_lifetime, type_args.first()) {
+                        (Some(lt), Some(ty)) => Some(ParsedType::CowType(
+                            lt.clone(),
+                            Box::from(parse_field_type(ty)),
+                        )),
+                        _ => None,
+                    }
+                }
+                "PathBuf" | "std::path::PathBuf" => Some(ParsedType::PathBufType),
+                "Duration" | "std::time::Duration" | "core::time::Duration" => {
+                    Some(ParsedType::DurationType)
+                }
+                "NonZero" | "std::num::NonZero" | "core::num::NonZero" => type_args
+                    .first()
+                    .map(|ty| ParsedType::NonZeroType(Box::from(parse_field_type(ty)))),
+                _ => parse_non_zero_primitive_type(full_type_path)
+                    .map(|ty| ParsedType::NonZeroType(Box::from(parse_field_type(&ty)))),
+            };
+
+            ParsedFieldType {
+                field_type: field_type.clone(),
+                parsed_type,
+                lifetime: None,
+            }
+        }
+        Type::Reference(ref type_ref) => ParsedFieldType {
+            lifetime: type_ref.lifetime.clone(),
+            field_type: field_type.clone(),
+            parsed_type: match *type_ref.elem {
+                Type::Path(ref path) if path.path.is_ident("str") => Some(ParsedType::StringType),
+                _ => None,
+            },
+        },
+        Type::Array(ref type_array) => ParsedFieldType {
+            field_type: field_type.clone(),
+            parsed_type: Some(ParsedType::ArrayType(Box::from(parse_field_type(
+                &type_array.elem,
+            )))),
+            lifetime: None,
+        },
+        _ => ParsedFieldType {
+            field_type: field_type.clone(),
+            parsed_type: None,
+            lifetime: None,
+        },
+    }
+}
+
+fn parse_non_zero_primitive_type(full_type_path: &str) -> Option<Type> {
+    let type_name = full_type_path
+        .strip_prefix("std::num::")
+        .or_else(|| full_type_path.strip_prefix("core::num::"))
+        .unwrap_or(full_type_path);
+
+    match type_name.strip_prefix("NonZero")?.to_lowercase().as_str() {
+        primitive @ ("i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32"
+        | "u64" | "u128" | "usize") => syn::parse_str(primitive).ok(),
+        _ => None,
+    }
+}
+
+fn parse_struct_builder_attrs(attrs: &[Attribute]) -> Result<ParsedStructBuilderAttrs> {
+    let mut builder_attrs = ParsedStructBuilderAttrs::default();
+
+    for attr in attrs.iter().filter(|a| a.path().is_ident("builder")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("merge") {
+                builder_attrs.merge = true;
+                Ok(())
+            } else if meta.path.is_ident("apply") {
+                builder_attrs.apply = true;
+                Ok(())
+            } else if meta.path.is_ident("from_field") {
+                builder_attrs.from_field = true;
+                Ok(())
+            } else if meta.path.is_ident("with_defaults") {
+                builder_attrs.with_defaults = true;
+                Ok(())
+            } else if meta.path.is_ident("staged") {
+                builder_attrs.staged = true;
+                Ok(())
+            } else if meta.path.is_ident("typestate") {
+                builder_attrs.typestate = true;
+                Ok(())
+            } else if meta.path.is_ident("option_naming") {
+                let option_naming: LitStr = meta.value()?.parse()?;
+                builder_attrs.explicit_option_naming = match option_naming.value().as_str() {
+                    "default" => false,
+                    "explicit" => true,
+                    _ => {
+                        return Err(Error::new_spanned(
+                            option_naming,
+                            "option_naming must be either \"default\" or \"explicit\"",
+                        ))
+                    }
+                };
+                Ok(())
+            } else if meta.path.is_ident("derive_default") {
+                builder_attrs.derive_default = true;
+                Ok(())
+            } else if meta.path.is_ident("field_defaults") {
+                meta.parse_nested_meta(|field_meta| {
+                    if field_meta.path.is_ident("into") {
+                        builder_attrs.into = true;
+                        Ok(())
+                    } else {
+                        Err(field_meta.error("unsupported builder field_defaults attribute"))
+                    }
+                })
+            } else if meta.path.is_ident("init_derive") {
+                meta.parse_nested_meta(|derive_meta| {
+                    builder_attrs.init_derive.push(derive_meta.path);
+                    Ok(())
+                })
+            } else if meta.path.is_ident("pattern") {
+                let pattern: LitStr = meta.value()?.parse()?;
+                builder_attrs.setters_pattern = match pattern.value().as_str() {
+                    "both" => SettersPattern::Both,
+                    "owned" => SettersPattern::Owned,
+                    "mutable" => SettersPattern::Mutable,
+                    _ => {
+                        return Err(Error::new_spanned(
+                            pattern,
+                            "pattern must be one of \"both\", \"owned\" or \"mutable\"",
+                        ))
+                    }
+                };
+                Ok(())
+            } else if meta.path.is_ident("doc_examples") {
+                builder_attrs.doc_examples = true;
+                Ok(())
+            } else if meta.path.is_ident("diff") {
+                builder_attrs.diff = true;
+                Ok(())
+            } else if meta.path.is_ident("trace") {
+                builder_attrs.trace = true;
+                Ok(())
+            } else if meta.path.is_ident("clone_setters") {
+                builder_attrs.clone_setters = true;
+                Ok(())
+            } else if meta.path.is_ident("mutable_with") {
+                builder_attrs.mutable_with = true;
+                Ok(())
+            } else if meta.path.is_ident("no_inline") {
+                builder_attrs.no_inline = true;
+                Ok(())
+            } else if meta.path.is_ident("name") {
+                let name: LitStr = meta.value()?.parse()?;
+                builder_attrs.name = Some(name.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("setter_prefix") {
+                let setter_prefix: LitStr = meta.value()?.parse()?;
+                builder_attrs.setter_prefix = Some(setter_prefix.value());
+                Ok(())
+            } else if meta.path.is_ident("complete_when_all_set") {
+                builder_attrs.complete_when_all_set = true;
+                Ok(())
+            } else if meta.path.is_ident("const_new") {
+                builder_attrs.const_new = true;
+                Ok(())
+            } else if meta.path.is_ident("vis") {
+                let vis: LitStr = meta.value()?.parse()?;
+                builder_attrs.vis = Some(vis.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("init_name") {
+                let init_name: LitStr = meta.value()?.parse()?;
+                builder_attrs.init_name = Some(init_name.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported builder struct attribute"))
+            }
+        })?;
+    }
+
+    if builder_attrs.clone_setters && builder_attrs.mutable_with {
+        return Err(Error::new(
+            Span::call_site(),
+            "builder(clone_setters) can't be used with builder(mutable_with)",
+        ));
+    }
+
+    builder_attrs.non_exhaustive = attrs.iter().any(|a| a.path().is_ident("non_exhaustive"));
+
+    Ok(builder_attrs)
+}
+
+fn parse_fields(fields: &FieldsNamed) -> Result<Vec<ParsedField>> {
+    let parsed_fields = fields
+        .named
+        .iter()
+        .map(parse_field)
+        .collect::<Result<Vec<ParsedField>>>()?;
+    check_default_field_references(fields)?;
+    Ok(parsed_fields)
+}
+
+fn check_default_field_references(fields: &FieldsNamed) -> Result<()> {
+    let field_names: Vec<&Ident> = fields
+        .named
+        .iter()
+        .filter_map(|f| f.ident.as_ref())
+        .collect();
+
+    for field in fields.named.iter() {
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("default")) {
+            if let Meta::NameValue(MetaNameValue {
+                value:
+                    Expr::Lit(ExprLit {
+                        lit: Lit::Str(ref lit_str),
+                        ..
+                    }),
+                ..
+            }) = attr.meta
+            {
+                let default_expr: Expr = syn::parse_str(&lit_str.value())?;
+                if let Some(field_name) = find_field_reference(&default_expr, &field_names) {
+                    return Err(Error::new_spanned(
+                        lit_str,
+                        format!(
+                            "default expression can't reference the `{}` field, defaults are evaluated without access to other fields",
+                            field_name
+                        ),
+                    ));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+// Only the receiver chain of the expression is checked, because closures, blocks and patterns
+// inside it can bind their own variables named like fields
+fn find_field_reference<'a>(expr: &Expr, field_names: &[&'a Ident]) -> Option<&'a Ident> {
+    match expr {
+        Expr::Path(ExprPath {
+            qself: None,
+            ref path,
+            ..
+        }) => path
+            .get_ident()
+            .and_then(|ident| field_names.iter().copied().find(|f| *f == ident)),
+        Expr::MethodCall(ref call) => find_field_reference(&call.receiver, field_names),
+        Expr::Field(ref field) => find_field_reference(&field.base, field_names),
+        Expr::Index(ref index) => find_field_reference(&index.expr, field_names),
+        Expr::Reference(ref reference) => find_field_reference(&reference.expr, field_names),
+        Expr::Unary(ref unary) => find_field_reference(&unary.expr, field_names),
+        Expr::Paren(ref paren) => find_field_reference(&paren.expr, field_names),
+        Expr::Cast(ref cast) => find_field_reference(&cast.expr, field_names),
+        Expr::Try(ref try_expr) => find_field_reference(&try_expr.expr, field_names),
+        Expr::Binary(ref binary) => find_field_reference(&binary.left, field_names)
+            .or_else(|| find_field_reference(&binary.right, field_names)),
+        _ => None,
+    }
+}
+
+fn parse_field(field: &Field) -> Result<ParsedField> {
+    let builder_attrs = parse_field_builder_attrs(field)?;
+
+    let default_tokens = match (
+        parse_field_default_attr(field)?,
+        builder_attrs.default_fn.as_ref(),
+        builder_attrs.default_const.as_ref(),
+        builder_attrs.default_try.as_ref(),
+    ) {
+        (default_tokens, None, None, None) => default_tokens,
+        (None, Some(default_fn), None, None) => Some(quote! { #default_fn() }),
+        (None, None, Some(default_const), None) => Some(quote! { Self::#default_const }),
+        (None, None, None, Some(default_try)) => {
+            let default_error = format!(
+                "invalid default value for the {} field",
+                field
+                    .ident
+                    .as_ref()
+                    .map(|ident| ident.to_string())
+                    .unwrap_or_default()
+            );
+            Some(quote! { (#default_try).expect(#default_error) })
+        }
+        _ => return Err(Error::new_spanned(
+            field,
+            "only one of default, builder(default_fn), builder(default_const) and builder(default_try) can be used",
+        )),
+    };
+
+    let parsed_field_type = parse_field_type(&field.ty);
+
+    if builder_attrs.required
+        && !parsed_field_type
+            .parsed_type
+            .as_ref()
+            .is_some_and(|t| t.is_option())
+    {
+        return Err(Error::new_spanned(
+            field,
+            "builder(required) can be used only on Option<> fields",
+        ));
+    }
+
+    if builder_attrs.strip_bool && !is_bool_type(&parsed_field_type.field_type) {
+        return Err(Error::new_spanned(
+            field,
+            "builder(setter(strip_bool)) can be used only on bool fields",
+        ));
+    }
+
+    if builder_attrs.each.is_some() {
+        let item_parsed_type = match parsed_field_type.parsed_type.as_ref() {
+            Some(ParsedType::OptionalType(ga_type_box)) => ga_type_box.parsed_type.as_ref(),
+            parsed_type => parsed_type,
+        };
+        if !matches!(
+            item_parsed_type,
+            Some(ParsedType::VecType(_)) | Some(ParsedType::SetType(_))
+        ) {
+            return Err(Error::new_spanned(
+                field,
+                "builder(each) can be used only on Vec<> and set fields",
+            ));
+        }
+    }
+
+    Ok(ParsedField {
+        ident: field.ident.as_ref().unwrap().clone(),
+        parsed_field_type,
+        default_tokens,
+        visibility: field.vis.clone(),
+        builder_attrs,
+        doc_attrs: field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("doc"))
+            .cloned()
+            .collect(),
+        serde_attrs: field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("serde"))
+            .cloned()
+            .collect(),
+    })
+}
+
+fn parse_field_builder_attrs(field: &Field) -> Result<ParsedFieldBuilderAttrs> {
+    let mut builder_attrs = ParsedFieldBuilderAttrs::default();
+
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("builder")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("into") {
+                builder_attrs.into = if meta.input.peek(Token![=]) {
+                    let into: LitBool = meta.value()?.parse()?;
+                    Some(into.value)
+                } else {
+                    Some(true)
+                };
+                Ok(())
+            } else if meta.path.is_ident("getter") {
+                builder_attrs.getter = true;
+                Ok(())
+            } else if meta.path.is_ident("skip") {
+                builder_attrs.skip = true;
+                Ok(())
+            } else if meta.path.is_ident("skip_setters") {
+                builder_attrs.skip_setters = true;
+                Ok(())
+            } else if meta.path.is_ident("required") {
+                builder_attrs.required = true;
+                Ok(())
+            } else if meta.path.is_ident("setter") {
+                meta.parse_nested_meta(|setter_meta| {
+                    if setter_meta.path.is_ident("strip_bool") {
+                        builder_attrs.strip_bool = true;
+                        Ok(())
+                    } else {
+                        Err(setter_meta.error("unsupported builder setter attribute"))
+                    }
+                })
+            } else if meta.path.is_ident("each") {
+                let each: LitStr = meta.value()?.parse()?;
+                builder_attrs.each = Some(each.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("rename") {
+                let rename: LitStr = meta.value()?.parse()?;
+                builder_attrs.rename = Some(rename.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("default_const") {
+                let default_const: LitStr = meta.value()?.parse()?;
+                builder_attrs.default_const = Some(default_const.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("default_try") {
+                let default_try: LitStr = meta.value()?.parse()?;
+                builder_attrs.default_try = Some(default_try.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("try_from") {
+                let try_from: LitStr = meta.value()?.parse()?;
+                builder_attrs.try_from = Some(try_from.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("default_fn") {
+                let default_fn: LitStr = meta.value()?.parse()?;
+                builder_attrs.default_fn = Some(default_fn.parse()?);
+                Ok(())
+            } else if meta.path.is_ident("validate") {
+                let validate_fn: LitStr = meta.value()?.parse()?;
+                builder_attrs.validate = Some(validate_fn.parse()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported builder field attribute"))
+            }
+        })?;
+    }
+
+    Ok(builder_attrs)
+}
+
+fn generate_fields_functions(
+    fields: &[ParsedField],
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .map(|f| generate_field_methods(f, struct_attrs))
+        .collect()
+}
+
+// Helpers like `with_{}_opt` or `with_{}_secs` can produce the name of another field setter
+fn check_generated_method_names<'a>(
+    methods: impl Iterator<Item = (Option<&'a Ident>, &'a proc_macro2::TokenStream)>,
+) -> Result<()> {
+    let mut method_owners: Vec<(Ident, Option<&Ident>)> = Vec::new();
+
+    for (owner, tokens) in methods {
+        let tokens: Vec<proc_macro2::TokenTree> = tokens.clone().into_iter().collect();
+        for window in tokens.windows(2) {
+            if let [proc_macro2::TokenTree::Ident(ref keyword), proc_macro2::TokenTree::Ident(ref method_name)] =
+                window
+            {
+                if keyword != "fn" {
+                    continue;
+                }
+                if let Some((_, existing_owner)) =
+                    method_owners.iter().find(|(name, _)| name == method_name)
+                {
+                    let field_name = owner.map(|ident| ident.to_string()).unwrap_or_default();
+                    let message = match existing_owner {
+                        Some(existing_owner) => format!(
+                            "method `{}` generated for the `{}` field clashes with the one generated for the `{}` field",
+                            method_name, field_name, existing_owner
+                        ),
+                        None => format!(
+                            "method `{}` generated for the `{}` field clashes with a generated struct method",
+                            method_name, field_name
+                        ),
+                    };
+                    return Err(match owner {
+                        Some(owner) => Error::new_spanned(owner, message),
+                        None => Error::new(Span::call_site(), message),
+                    });
+                }
+                method_owners.push((method_name.clone(), owner));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn generate_field_methods(
+    field: &ParsedField,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> proc_macro2::TokenStream {
+    let generated_setters = generate_field_functions(field, struct_attrs);
+    let generated_getter = generate_field_getter(field, struct_attrs);
+    quote! {
+        #generated_setters
+        #generated_getter
+    }
+}
+
+fn generate_field_getter(
+    field: &ParsedField,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> proc_macro2::TokenStream {
+    if !field.builder_attrs.getter {
+        return quote! {};
+    }
+
+    let field_name = &field.ident;
+    let setter_name = field.setter_name();
+    let get_field_name = format_ident!("get_{}", setter_name);
+    let methods_visibility = struct_attrs.methods_visibility();
+    let inline_attr = struct_attrs.inline_attr();
+    let field_type = &field.parsed_field_type.field_type;
+
+    match field.parsed_field_type.parsed_type.as_ref() {
+        Some(ParsedType::OptionalType(ga_type_box)) => {
+            let ga_type = &ga_type_box.field_type;
+            quote! {
+                #inline_attr
+                #methods_visibility fn #get_field_name(&self) -> Option<&#ga_type> {
+                    self.#field_name.as_ref()
+                }
+            }
+        }
+        _ => {
+            quote! {
+                #inline_attr
+                #methods_visibility fn #get_field_name(&self) -> &#field_type {
+                    &self.#field_name
+                }
+            }
+        }
+    }
+}
+
+fn generate_field_functions(
+    field: &ParsedField,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> proc_macro2::TokenStream {
+    if field.builder_attrs.skip_setters {
+        return quote! {};
+    }
+
+    let field_name = &field.ident;
+    let setter_name = field.setter_name();
+    let set_field_name = struct_attrs.mutable_setter_name(setter_name);
+    let inline_attr = struct_attrs.inline_attr();
+    let reset_field_name = format_ident!("reset_{}", setter_name);
+    let without_field_name = format_ident!("without_{}", setter_name);
+    let clear_field_name = struct_attrs.option_setter_name("reset_", "clear_", setter_name);
+    let opt_field_name = struct_attrs.option_setter_name("opt_", "maybe_", setter_name);
+    let mut_opt_field_name = struct_attrs.option_setter_name("mopt_", "set_maybe_", setter_name);
+    let map_field_name = format_ident!("map_{}", setter_name);
+    let with_field_if_name = format_ident!("with_{}_if", setter_name);
+    let with_field_or_name = format_ident!("with_{}_or", setter_name);
+    let with_field_opt_name = format_ident!("with_{}_opt", setter_name);
+    let with_field_from_name = format_ident!("with_{}_from", setter_name);
+    let take_field_name = format_ident!("take_{}", setter_name);
+    let get_or_insert_field_name = format_ident!("get_or_insert_{}", setter_name);
+
+    let set_field_doc = generate_doc_example(struct_attrs, &set_field_name, "value");
+    let clear_field_doc = generate_doc_example(struct_attrs, &clear_field_name, "");
+    let reset_field_doc = generate_doc_example(struct_attrs, &reset_field_name, "");
+    let mut_opt_field_doc = generate_doc_example(struct_attrs, &mut_opt_field_name, "Some(value)");
+    let take_field_doc = generate_doc_example(struct_attrs, &take_field_name, "");
+    let get_or_insert_field_doc =
+        generate_doc_example(struct_attrs, &get_or_insert_field_name, "default");
+    let with_field_if_doc = generate_doc_example(struct_attrs, &with_field_if_name, "true, value");
+    let with_field_or_doc = generate_doc_example(struct_attrs, &with_field_or_name, "value");
+    let without_field_doc = generate_doc_example(struct_attrs, &without_field_name, "");
+    let opt_field_doc = generate_doc_example(struct_attrs, &opt_field_name, "Some(value)");
+    let map_field_doc = generate_doc_example(struct_attrs, &map_field_name, "|value| value");
+    let with_field_opt_doc =
+        generate_doc_example(struct_attrs, &with_field_opt_name, "Some(value)");
+    let with_field_from_doc = generate_doc_example(struct_attrs, &with_field_from_name, "value");
+
+    let field_type = &field.parsed_field_type.field_type;
+    let field_visibility = &field.visibility;
+    let setter_trace = generate_setter_trace(field, struct_attrs);
+
+    match field.parsed_field_type.parsed_type.as_ref() {
+        Some(ParsedType::OptionalType(ga_type_box)) => {
+            let parsed_ga_field_type: &ParsedFieldType = ga_type_box;
+            let ga_type = &parsed_ga_field_type.field_type;
+            let (value_type, value) = generate_wrapped_setter_value(field, parsed_ga_field_type);
+            let generated_with_setter =
+                generate_with_setter(field, struct_attrs, &value_type, &quote! { Some(#value) });
+            let generated_type_helpers = generate_field_type_helpers(field, struct_attrs);
+
+            let mutable_setters = quote! {
+                #set_field_doc
+                #inline_attr
+                #field_visibility fn #set_field_name(&mut self, value : #value_type) -> &mut Self {
+                    self.#field_name = Some(#value);
+                    #setter_trace
+                    self
+                }
+
+                #clear_field_doc
+                #inline_attr
+                #field_visibility fn #clear_field_name(&mut self) -> &mut Self {
+                    self.#field_name = None;
+                    #setter_trace
+                    self
+                }
+
+                #mut_opt_field_doc
+                #inline_attr
+                #field_visibility fn #mut_opt_field_name(&mut self, value : #field_type) -> &mut Self {
+                    self.#field_name = value;
+                    #setter_trace
+                    self
+                }
+
+                #take_field_doc
+                #inline_attr
+                #field_visibility fn #take_field_name(&mut self) -> Option<#ga_type> {
+                    self.#field_name.take()
+                }
+
+                #get_or_insert_field_doc
+                #inline_attr
+                #field_visibility fn #get_or_insert_field_name(&mut self, default : #ga_type) -> &mut #ga_type {
+                    self.#field_name.get_or_insert(default)
+                }
+            };
+            let owned_setters = quote! {
+                #generated_with_setter
+
+                #with_field_if_doc
+                #[must_use]
+                #inline_attr
+                #field_visibility fn #with_field_if_name(self, cond : bool, value : #value_type) -> Self {
+                    if cond {
+                        Self {
+                            #field_name : Some(#value),
+                            .. self
+                        }
+                    } else {
+                        self
+                    }
+                }
+
+                #with_field_or_doc
+                #[must_use]
+                #inline_attr
+                #field_visibility fn #with_field_or_name(mut self, value : #value_type) -> Self {
+                    if self.#field_name.is_none() {
+                        self.#field_name = Some(#value);
+                    }
+                    self
+                }
+
+                #with_field_from_doc
+                #[must_use]
+                #inline_attr
+                #field_visibility fn #with_field_from_name(self, value : impl Into<#ga_type>) -> Self {
+                    Self {
+                        #field_name : Some(value.into()),
+                        .. self
+                    }
+                }
+
+                #without_field_doc
+                #[must_use]
+                #inline_attr
+                #field_visibility fn #without_field_name(self) -> Self {
+                    Self {
+                        #field_name : None,
+                        .. self
+                    }
+                }
+
+                #opt_field_doc
+                #[must_use]
+                #inline_attr
+                #field_visibility fn #opt_field_name(self, value : #field_type) -> Self {
+                    Self {
+                        #field_name : value,
+                        .. self
+                    }
+                }
+
+                #map_field_doc
+                #[must_use]
+                #inline_attr
+                #field_visibility fn #map_field_name(mut self, f : impl FnOnce(#ga_type) -> #ga_type) -> Self {
+                    self.#field_name = self.#field_name.map(f);
+                    self
+                }
+            };
+
+            let generated_setters = struct_attrs.setters_by_pattern(mutable_setters, owned_setters);
+
+            quote! {
+                #generated_setters
+
+                #generated_type_helpers
+            }
+        }
+        _ => {
+            let (value_type, value) =
+                generate_wrapped_setter_value(field, &field.parsed_field_type);
+            let generated_with_setter =
+                generate_with_setter(field, struct_attrs, &value_type, &value);
+            let generated_type_helpers = generate_field_type_helpers(field, struct_attrs);
+            let generated_reset_to_default = match field.default_tokens.as_ref() {
+                Some(default_value) => quote! {
+                    #reset_field_doc
+                    #inline_attr
+                    #field_visibility fn #reset_field_name(&mut self) -> &mut Self {
+                        self.#field_name = #default_value;
+                        #setter_trace
+                        self
+                    }
+                },
+                None => quote! {},
+            };
+
+            let mutable_setters = quote! {
+                #set_field_doc
+                #inline_attr
+                #field_visibility fn #set_field_name(&mut self, value : #value_type) -> &mut Self {
+                    self.#field_name = #value;
+                    #setter_trace
+                    self
+                }
+
+                #generated_reset_to_default
+            };
+            let owned_setters = quote! {
+                #generated_with_setter
+
+                #with_field_if_doc
+                #[must_use]
+                #inline_attr
+                #field_visibility fn #with_field_if_name(self, cond : bool, value : #value_type) -> Self {
+                    if cond {
+                        Self {
+                            #field_name : #value,
+                            .. self
+                        }
+                    } else {
+                        self
+                    }
+                }
+
+                #with_field_from_doc
+                #[must_use]
+                #inline_attr
+                #field_visibility fn #with_field_from_name(self, value : impl Into<#field_type>) -> Self {
+                    Self {
+                        #field_name : value.into(),
+                        .. self
+                    }
+                }
+
+                #with_field_opt_doc
+                #[must_use]
+                #inline_attr
+                #field_visibility fn #with_field_opt_name(self, value : Option<#value_type>) -> Self {
+                    match value {
+                        Some(value) => Self {
+                            #field_name : #value,
+                            .. self
+                        },
+                        None => self,
+                    }
+                }
+
+                #map_field_doc
+                #[must_use]
+                #inline_attr
+                #field_visibility fn #map_field_name(mut self, f : impl FnOnce(#field_type) -> #field_type) -> Self {
+                    self.#field_name = f(self.#field_name);
+                    self
+                }
+            };
+
+            let generated_setters = struct_attrs.setters_by_pattern(mutable_setters, owned_setters);
+
+            quote! {
+                #generated_setters
+
+                #generated_type_helpers
+            }
+        }
+    }
+}
+
+fn generate_with_setter(
+    field: &ParsedField,
+    struct_attrs: &ParsedStructBuilderAttrs,
+    value_type: &proc_macro2::TokenStream,
+    value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_name = &field.ident;
+    let with_field_name = format_ident!("with_{}", field.setter_name());
+    let field_visibility = &field.visibility;
+    let doc_attrs = &field.doc_attrs;
+    let doc_example = generate_doc_example(struct_attrs, &with_field_name, "value");
+    let inline_attr = struct_attrs.inline_attr();
+    let setter_trace = generate_setter_trace(field, struct_attrs);
+
+    if field.builder_attrs.strip_bool {
+        return generate_strip_bool_with_setters(field, struct_attrs, value_type, value);
+    }
+
+    if struct_attrs.mutable_with {
+        quote! {
+            #(#doc_attrs)*
+            #doc_example
+            #inline_attr
+            #field_visibility fn #with_field_name(&mut self, value : #value_type) -> &mut Self {
+                self.#field_name = #value;
+                #setter_trace
+                self
+            }
+        }
+    } else if struct_attrs.clone_setters {
+        quote! {
+            #(#doc_attrs)*
+            #doc_example
+            #[must_use]
+            #inline_attr
+            #field_visibility fn #with_field_name(&self, value : #value_type) -> Self {
+                Self {
+                    #field_name : #value,
+                    .. self.clone()
+                }
+            }
+        }
+    } else {
+        quote! {
+            #(#doc_attrs)*
+            #doc_example
+            #[must_use]
+            #inline_attr
+            #field_visibility fn #with_field_name(self, value : #value_type) -> Self {
+                Self {
+                    #field_name : #value,
+                    .. self
+                }
+            }
+        }
+    }
+}
+
+fn generate_strip_bool_with_setters(
+    field: &ParsedField,
+    struct_attrs: &ParsedStructBuilderAttrs,
+    value_type: &proc_macro2::TokenStream,
+    value: &proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let field_name = &field.ident;
+    let with_field_name = format_ident!("with_{}", field.setter_name());
+    let with_field_value_name = format_ident!("with_{}_value", field.setter_name());
+    let field_visibility = &field.visibility;
+    let doc_attrs = &field.doc_attrs;
+    let doc_example = generate_doc_example(struct_attrs, &with_field_name, "");
+    let value_doc_example = generate_doc_example(struct_attrs, &with_field_value_name, "value");
+    let inline_attr = struct_attrs.inline_attr();
+    let setter_trace = generate_setter_trace(field, struct_attrs);
+
+    if struct_attrs.mutable_with {
+        quote! {
+            #(#doc_attrs)*
+            #doc_example
+            #inline_attr
+            #field_visibility fn #with_field_name(&mut self) -> &mut Self {
+                self.#field_name = true;
+                #setter_trace
+                self
+            }
+
+            #value_doc_example
+            #inline_attr
+            #field_visibility fn #with_field_value_name(&mut self, value : #value_type) -> &mut Self {
+                self.#field_name = #value;
+                #setter_trace
+                self
+            }
+        }
+    } else if struct_attrs.clone_setters {
+        quote! {
+            #(#doc_attrs)*
+            #doc_example
+            #[must_use]
+            #inline_attr
+            #field_visibility fn #with_field_name(&self) -> Self {
+                Self {
+                    #field_name : true,
+                    .. self.clone()
+                }
+            }
+
+            #value_doc_example
+            #[must_use]
+            #inline_attr
+            #field_visibility fn #with_field_value_name(&self, value : #value_type) -> Self {
+                Self {
+                    #field_name : #value,
+                    .. self.clone()
+                }
+            }
+        }
+    } else {
+        quote! {
+            #(#doc_attrs)*
+            #doc_example
+            #[must_use]
+            #inline_attr
+            #field_visibility fn #with_field_name(self) -> Self {
+                Self {
+                    #field_name : true,
+                    .. self
+                }
+            }
+
+            #value_doc_example
+            #[must_use]
+            #inline_attr
+            #field_visibility fn #with_field_value_name(self, value : #value_type) -> Self {
+                Self {
+                    #field_name : #value,
+                    .. self
+                }
+            }
+        }
+    }
+}
+
+fn generate_doc_example(
+    struct_attrs: &ParsedStructBuilderAttrs,
+    method_name: &Ident,
+    args: &str,
+) -> proc_macro2::TokenStream {
+    if !struct_attrs.doc_examples {
+        return quote! {};
+    }
+
+    let example = format!(
+        "Example: `builder.{}({})`",
+        method_name.to_string().trim_start_matches("r#"),
+        args
+    );
+    quote! {
+        #[doc = ""]
+        #[doc = #example]
+    }
+}
+
+fn generate_field_type_helpers(
+    field: &ParsedField,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> proc_macro2::TokenStream {
+    let (parsed_value_type, optional) = match field.parsed_field_type.parsed_type.as_ref() {
+        Some(ParsedType::OptionalType(ga_type_box)) => (ga_type_box.as_ref(), true),
+        _ => (&field.parsed_field_type, false),
+    };
+
+    let generated_value_type_helpers =
+        generate_value_type_helpers(field, parsed_value_type, optional, struct_attrs);
+    let generated_try_from_setter =
+        generate_try_from_setter(field, parsed_value_type, optional, struct_attrs);
+
+    quote! {
+        #generated_value_type_helpers
+
+        #generated_try_from_setter
+    }
+}
+
+fn generate_try_from_setter(
+    field: &ParsedField,
+    parsed_value_type: &ParsedFieldType,
+    optional: bool,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> proc_macro2::TokenStream {
+    let source_type = match field.builder_attrs.try_from.as_ref() {
+        Some(source_type) => source_type,
+        None => return quote! {},
+    };
+
+    let field_name = &field.ident;
+    let field_visibility = &field.visibility;
+    let value_type = &parsed_value_type.field_type;
+    let inline_attr = struct_attrs.inline_attr();
+    let try_with_field_name = format_ident!("try_with_{}", field.setter_name());
+    let field_value = if optional {
+        quote! { Some(value) }
+    } else {
+        quote! { value }
+    };
+
+    struct_attrs.setters_by_pattern(
+        quote! {},
+        quote! {
+            #inline_attr
+            #field_visibility fn #try_with_field_name(self, value : #source_type) -> std::result::Result<Self, <#value_type as std::convert::TryFrom<#source_type>>::Error> {
+                let value = <#value_type as std::convert::TryFrom<#source_type>>::try_from(value)?;
+                Ok(Self {
+                    #field_name : #field_value,
+                    .. self
+                })
+            }
+        },
+    )
+}
+
+fn generate_value_type_helpers(
+    field: &ParsedField,
+    parsed_value_type: &ParsedFieldType,
+    optional: bool,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> proc_macro2::TokenStream {
+    let inline_attr = struct_attrs.inline_attr();
+    let field_name = &field.ident;
+    let setter_name = field.setter_name();
+    let field_type = &parsed_value_type.field_type;
+    let field_visibility = &field.visibility;
+    let setter_trace = generate_setter_trace(field, struct_attrs);
+
+    let (field_access, field_value) = if optional {
+        (
+            quote! { self.#field_name.get_or_insert_with(Default::default) },
+            quote! { Some(value) },
+        )
+    } else {
+        (quote! { self.#field_name }, quote! { value })
+    };
+
+    let (mutable_helpers, owned_helpers) = match parsed_value_type.parsed_type.as_ref() {
+        Some(ParsedType::ScalarType) => {
+            let with_field_ref_name = format_ident!("with_{}_ref", setter_name);
+            let toggle_helper = if !optional && is_bool_type(field_type) {
+                let toggle_field_name = format_ident!("toggle_{}", setter_name);
+                quote! {
+                    #inline_attr
+                    #field_visibility fn #toggle_field_name(&mut self) -> &mut Self {
+                        self.#field_name = !self.#field_name;
+                        #setter_trace
+                        self
+                    }
+                }
+            } else {
+                quote! {}
+            };
+            (
+                toggle_helper,
+                quote! {
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #with_field_ref_name(self, value : &#field_type) -> Self {
+                        let value = *value;
+                        Self {
+                            #field_name : #field_value,
+                            .. self
+                        }
+                    }
+                },
+            )
+        }
+        Some(ParsedType::VecType(item_type_box)) => {
+            let item_type = &item_type_box.field_type;
+            let (mutable_extend_helpers, owned_extend_helpers) =
+                generate_extend_helpers(field, item_type, &field_access, struct_attrs);
+            let push_field_name = format_ident!("push_{}", setter_name);
+            let with_field_item_name = field.item_setter_name();
+            (
+                quote! {
+                    #inline_attr
+                    #field_visibility fn #push_field_name(&mut self, item : #item_type) -> &mut Self {
+                        #field_access.push(item);
+                        #setter_trace
+                        self
+                    }
+
+                    #mutable_extend_helpers
+                },
+                quote! {
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #with_field_item_name(mut self, item : #item_type) -> Self {
+                        #field_access.push(item);
+                        self
+                    }
+
+                    #owned_extend_helpers
+                },
+            )
+        }
+        Some(ParsedType::DurationType) => {
+            let with_field_millis_name = format_ident!("with_{}_millis", setter_name);
+            let with_field_secs_name = format_ident!("with_{}_secs", setter_name);
+            (
+                quote! {},
+                quote! {
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #with_field_millis_name(self, millis : u64) -> Self {
+                        let value = std::time::Duration::from_millis(millis);
+                        Self {
+                            #field_name : #field_value,
+                            .. self
+                        }
+                    }
+
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #with_field_secs_name(self, secs : u64) -> Self {
+                        let value = std::time::Duration::from_secs(secs);
+                        Self {
+                            #field_name : #field_value,
+                            .. self
+                        }
+                    }
+                },
+            )
+        }
+        Some(ParsedType::SetType(item_type_box)) => {
+            let item_type = &item_type_box.field_type;
+            let (mutable_extend_helpers, owned_extend_helpers) =
+                generate_extend_helpers(field, item_type, &field_access, struct_attrs);
+            let insert_field_name = format_ident!("insert_{}", setter_name);
+            let with_field_item_name = field.item_setter_name();
+            (
+                quote! {
+                    #inline_attr
+                    #field_visibility fn #insert_field_name(&mut self, item : #item_type) -> &mut Self {
+                        #field_access.insert(item);
+                        #setter_trace
+                        self
+                    }
+
+                    #mutable_extend_helpers
+                },
+                quote! {
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #with_field_item_name(mut self, item : #item_type) -> Self {
+                        #field_access.insert(item);
+                        self
+                    }
+
+                    #owned_extend_helpers
+                },
+            )
+        }
+        Some(ParsedType::PointerType(pointer_kind, _)) => {
+            let pointer_suffix = pointer_kind.setter_suffix();
+            let set_field_pointer_name = format_ident!("{}_{}", setter_name, pointer_suffix);
+            let with_field_pointer_name = format_ident!("with_{}_{}", setter_name, pointer_suffix);
+            (
+                quote! {
+                    #inline_attr
+                    #field_visibility fn #set_field_pointer_name(&mut self, value : #field_type) -> &mut Self {
+                        self.#field_name = #field_value;
+                        #setter_trace
+                        self
+                    }
+                },
+                quote! {
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #with_field_pointer_name(self, value : #field_type) -> Self {
+                        Self {
+                            #field_name : #field_value,
+                            .. self
+                        }
+                    }
+                },
+            )
+        }
+        Some(ParsedType::NonZeroType(primitive_type_box))
+            if !optional && field.builder_attrs.try_from.is_none() =>
+        {
+            let primitive_type = &primitive_type_box.field_type;
+            let try_with_field_name = format_ident!("try_with_{}", setter_name);
+            let non_zero_error = format!("{} must be non-zero", setter_name);
+            (
+                quote! {},
+                quote! {
+                    #inline_attr
+                    #field_visibility fn #try_with_field_name(self, value : #primitive_type) -> std::result::Result<Self, String> {
+                        <#field_type>::new(value)
+                            .map(|value| Self {
+                                #field_name : value,
+                                .. self
+                            })
+                            .ok_or_else(|| #non_zero_error.to_string())
+                    }
+                },
+            )
+        }
+        Some(ParsedType::CowType(lifetime, borrowed_type_box)) => {
+            let borrowed_type = &borrowed_type_box.field_type;
+            let with_field_borrowed_name = format_ident!("with_{}_borrowed", setter_name);
+            let with_field_owned_name = format_ident!("with_{}_owned", setter_name);
+            (
+                quote! {},
+                quote! {
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #with_field_borrowed_name(self, value : &#lifetime #borrowed_type) -> Self {
+                        let value = std::borrow::Cow::Borrowed(value);
+                        Self {
+                            #field_name : #field_value,
+                            .. self
+                        }
+                    }
+
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #with_field_owned_name(self, value : <#borrowed_type as std::borrow::ToOwned>::Owned) -> Self {
+                        let value = std::borrow::Cow::Owned(value);
+                        Self {
+                            #field_name : #field_value,
+                            .. self
+                        }
+                    }
+                },
+            )
+        }
+        Some(ParsedType::PathBufType) => {
+            let with_field_path_name = format_ident!("with_{}_path", setter_name);
+            (
+                quote! {},
+                quote! {
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #with_field_path_name(self, path : impl AsRef<std::path::Path>) -> Self {
+                        let value = path.as_ref().to_path_buf();
+                        Self {
+                            #field_name : #field_value,
+                            .. self
+                        }
+                    }
+                },
+            )
+        }
+        Some(ParsedType::ArrayType(item_type_box)) if !optional => {
+            let item_type = &item_type_box.field_type;
+            let set_field_at_name = format_ident!("set_{}_at", setter_name);
+            (
+                quote! {
+                    #inline_attr
+                    #field_visibility fn #set_field_at_name(&mut self, idx : usize, value : #item_type) -> &mut Self {
+                        self.#field_name[idx] = value;
+                        #setter_trace
+                        self
+                    }
+                },
+                quote! {},
+            )
+        }
+        Some(ParsedType::MapType(key_type_box, value_type_box)) => {
+            let key_type = &key_type_box.field_type;
+            let value_type = &value_type_box.field_type;
+            let insert_field_name = format_ident!("insert_{}", setter_name);
+            let with_field_entry_name = format_ident!("with_{}_entry", setter_name);
+            (
+                quote! {
+                    #inline_attr
+                    #field_visibility fn #insert_field_name(&mut self, key : #key_type, value : #value_type) -> &mut Self {
+                        #field_access.insert(key, value);
+                        #setter_trace
+                        self
+                    }
+                },
+                quote! {
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #with_field_entry_name(mut self, key : #key_type, value : #value_type) -> Self {
+                        #field_access.insert(key, value);
+                        self
+                    }
+                },
+            )
+        }
+        _ => (quote! {}, quote! {}),
+    };
+
+    let take_helper = match parsed_value_type.parsed_type.as_ref() {
+        Some(
+            ParsedType::StringType
+            | ParsedType::VecType(_)
+            | ParsedType::SetType(_)
+            | ParsedType::MapType(_, _),
+        ) if !optional && !matches!(field_type, Type::Reference(_)) => {
+            // Borrowed `&str` fields are just copied, so only owned values are taken out
+            let take_field_name = format_ident!("take_{}", setter_name);
+            quote! {
+                #inline_attr
+                #field_visibility fn #take_field_name(&mut self) -> #field_type {
+                    std::mem::take(&mut self.#field_name)
+                }
+            }
+        }
+        _ => quote! {},
+    };
+
+    struct_attrs.setters_by_pattern(
+        quote! {
+            #mutable_helpers
+            #take_helper
+        },
+        owned_helpers,
+    )
+}
+
+fn generate_extend_helpers(
+    field: &ParsedField,
+    item_type: &Type,
+    field_access: &proc_macro2::TokenStream,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let inline_attr = struct_attrs.inline_attr();
+    let setter_trace = generate_setter_trace(field, struct_attrs);
+    let setter_name = field.setter_name();
+    let field_visibility = &field.visibility;
+    let extend_field_name = format_ident!("extend_{}", setter_name);
+    let with_field_extended_name = format_ident!("with_{}_extended", setter_name);
+
+    (
+        quote! {
+            #inline_attr
+            #field_visibility fn #extend_field_name(&mut self, items : impl IntoIterator<Item = #item_type>) -> &mut Self {
+                #field_access.extend(items);
+                #setter_trace
+                self
+            }
+        },
+        quote! {
+            #[must_use]
+            #inline_attr
+            #field_visibility fn #with_field_extended_name(mut self, items : impl IntoIterator<Item = #item_type>) -> Self {
+                #field_access.extend(items);
+                self
+            }
+        },
+    )
+}
+
+fn generate_setter_trace(
+    field: &ParsedField,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> proc_macro2::TokenStream {
+    if !struct_attrs.trace {
+        return quote! {};
+    }
+
+    let field_name = &field.ident;
+    let field_name_str = field_name.to_string().trim_start_matches("r#").to_string();
+    quote! {
+        log::debug!("{} = {:?}", #field_name_str, self.#field_name);
+    }
+}
+
+fn generate_setter_value(
+    field: &ParsedField,
+    value_type: &Type,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    if field.builder_attrs.into.unwrap_or(false) {
+        (quote! { impl Into<#value_type> }, quote! { value.into() })
+    } else {
+        (quote! { #value_type }, quote! { value })
+    }
+}
+
+fn generate_wrapped_setter_value(
+    field: &ParsedField,
+    parsed_value_type: &ParsedFieldType,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    match parsed_value_type.parsed_type.as_ref() {
+        Some(ParsedType::PointerType(pointer_kind, inner_type_box)) => {
+            let (inner_value_type, inner_value) =
+                generate_setter_value(field, &inner_type_box.field_type);
+            (inner_value_type, pointer_kind.wrap_value(inner_value))
+        }
+        _ => generate_setter_value(field, &parsed_value_type.field_type),
+    }
+}
+
+fn is_bool_type(field_type: &Type) -> bool {
+    matches!(field_type, Type::Path(ref path) if path.path.is_ident("bool"))
+}
+
+// Pointer setters take the pointee by value, which isn't possible for unsized types
+fn is_sized_type(field_type: &Type) -> bool {
+    match field_type {
+        Type::TraitObject(_) | Type::ImplTrait(_) | Type::Slice(_) => false,
+        Type::Path(ref path) => !path.path.segments.last().is_some_and(|segment| {
+            segment.arguments.is_empty()
+                && ["str", "Path", "OsStr", "CStr"].contains(&segment.ident.to_string().as_str())
+        }),
+        _ => true,
+    }
+}
+
+fn generate_factory_method(
+    fields: &Vec<ParsedField>,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> proc_macro2::TokenStream {
+    let methods_visibility = &struct_attrs.methods_visibility();
+    let factory_method_name = struct_attrs.factory_method_name();
+
+    let required_fields: Vec<ParsedField> = fields
+        .clone()
+        .into_iter()
+        .filter(|f| f.is_required_field())
+        .collect();
+
+    let generated_new_params = generate_new_params(&required_fields);
+    let generated_factory_assignments = generate_factory_assignments(fields, &quote! {});
+    let generated_try_factory_method = generate_try_factory_method(fields, methods_visibility);
+    let const_token = if struct_attrs.const_new {
+        quote! { const }
+    } else {
+        quote! {}
+    };
+
+    quote! {
+        #[allow(clippy::too_many_arguments)]
+        #methods_visibility #const_token fn #factory_method_name(#(#generated_new_params)*) -> Self {
+            Self {
+                #(#generated_factory_assignments)*
+            }
+        }
+
+        #generated_try_factory_method
+    }
+}
+
+fn generate_try_factory_method(
+    fields: &[ParsedField],
+    methods_visibility: &Visibility,
+) -> proc_macro2::TokenStream {
+    let required_fields: Vec<ParsedField> = fields
+        .iter()
+        .filter(|f| f.is_required_field())
+        .cloned()
+        .collect();
+
+    let generated_validations = generate_validations(&required_fields, &quote! {});
+
+    if generated_validations.is_empty() {
+        return quote! {};
+    }
 
-use proc_macro::TokenStream;
-use proc_macro2::Span;
-use quote::*;
-use std::ops::Index;
-use syn::*;
-
-#[proc_macro_derive(Builder, attributes(default))]
-pub fn struct_builder_macro(input: TokenStream) -> TokenStream {
-    let item: syn::Item = syn::parse(input).expect("failed to parse input");
-    let span = Span::call_site();
-    match item {
-        Item::Struct(ref struct_item) => match struct_item.fields {
-            Fields::Named(ref named_fields) => {
-                let struct_name = &struct_item.ident;
-                let struct_generic_params: Vec<&TypeParam> = struct_item
-                    .generics
-                    .params
-                    .iter()
-                    .map(|ga| match ga {
-                        GenericParam::Type(ref ty) => Some(ty),
-                        _ => None,
-                    })
-                    .flatten()
-                    .collect();
-
-                let struct_generic_params_idents: Vec<&Ident> =
-                    struct_generic_params.iter().map(|gp| &gp.ident).collect();
-
-                let struct_lifetime_params: Vec<&LifetimeDef> = struct_item
-                    .generics
-                    .params
-                    .iter()
-                    .map(|ga| match ga {
-                        GenericParam::Lifetime(ref lt) => Some(lt),
-                        _ => None,
-                    })
-                    .flatten()
-                    .collect();
-
-                let struct_generic_where_decl: proc_macro2::TokenStream = struct_item
-                    .generics
-                    .where_clause
-                    .as_ref()
-                    .map_or(quote! {}, |wh| quote! { #wh });
-
-                let struct_fields = parse_fields(named_fields);
-
-                let generated_factory_method = generate_factory_method(&struct_fields);
-                let generated_fields_methods = generate_fields_functions(&struct_fields);
+    let generated_new_params = generate_new_params(&required_fields);
+    let generated_factory_assignments = generate_factory_assignments(fields, &quote! {});
 
-                let generated_aux_init_struct = generate_init_struct(
-                    struct_name,
-                    &struct_fields,
-                    &struct_generic_params,
-                    &struct_generic_params_idents,
-                    &struct_lifetime_params,
-                    struct_item.generics.where_clause.as_ref(),
-                );
+    quote! {
+        #[allow(clippy::too_many_arguments)]
+        #methods_visibility fn try_new(#(#generated_new_params)*) -> std::result::Result<Self, String> {
+            #(#generated_validations)*
+            Ok(Self {
+                #(#generated_factory_assignments)*
+            })
+        }
+    }
+}
 
-                let struct_decl: proc_macro2::TokenStream = if struct_generic_params.is_empty()
-                    && struct_lifetime_params.is_empty()
-                {
+fn generate_validate_all_method(
+    fields: &[ParsedField],
+    methods_visibility: &Visibility,
+) -> proc_macro2::TokenStream {
+    let generated_checks: Vec<proc_macro2::TokenStream> = fields
+        .iter()
+        .filter_map(|f| {
+            f.builder_attrs.validate.as_ref().map(|validate_fn| {
+                let field_name = &f.ident;
+                if f.is_option() {
                     quote! {
-                        impl #struct_name
+                        if let Some(ref value) = self.#field_name {
+                            if let Err(err) = #validate_fn(value) {
+                                errors.push(err);
+                            }
+                        }
                     }
                 } else {
                     quote! {
-                        impl <#(#struct_lifetime_params),* #(#struct_generic_params),* > #struct_name <#(#struct_lifetime_params),*  #(#struct_generic_params_idents),* > #struct_generic_where_decl
-                    }
-                };
-
-                let output = quote! {
-                    #[allow(dead_code)]
-                    #[allow(clippy::needless_update)]
-                    #struct_decl {
-                        #generated_factory_method
-                        #(#generated_fields_methods)*
+                        if let Err(err) = #validate_fn(&self.#field_name) {
+                            errors.push(err);
+                        }
                     }
+                }
+            })
+        })
+        .collect();
 
-                    #generated_aux_init_struct
-                };
+    if generated_checks.is_empty() {
+        return quote! {};
+    }
 
-                output.into()
+    quote! {
+        #methods_visibility fn validate_all(&self) -> std::result::Result<(), Vec<String>> {
+            let mut errors: Vec<String> = Vec::new();
+            #(#generated_checks)*
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors)
             }
-            _ => Error::new(span, "Builder works only on the structs with named fields")
-                .to_compile_error()
-                .into(),
-        },
-        _ => Error::new(span, "Builder derive works only on structs")
-            .to_compile_error()
-            .into(),
+        }
     }
 }
 
-#[allow(clippy::enum_variant_names)]
-#[derive(Clone)]
-enum ParsedType {
-    StringType,
-    ScalarType,
-    OptionalType(Box<ParsedFieldType>),
+fn generate_validations(
+    required_fields: &[ParsedField],
+    values_source: &proc_macro2::TokenStream,
+) -> Vec<proc_macro2::TokenStream> {
+    required_fields
+        .iter()
+        .filter_map(|f| {
+            f.builder_attrs.validate.as_ref().map(|validate_fn| {
+                let param_name = &f.ident;
+                quote! {
+                    #validate_fn(&#values_source #param_name)?;
+                }
+            })
+        })
+        .collect()
 }
 
-impl ParsedType {
-    fn is_option(&self) -> bool {
-        matches!(self, ParsedType::OptionalType(_))
-    }
+fn generate_new_params(fields: &[ParsedField]) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .map(|f| {
+            let param_name = &f.ident;
+            let param_type = f.required_param_type();
+
+            quote! {
+                #param_name : #param_type,
+            }
+        })
+        .collect()
 }
 
-#[derive(Clone)]
-struct ParsedFieldType {
-    field_type: Type,
-    parsed_type: Option<ParsedType>,
-    lifetime: Option<Lifetime>,
+fn generate_factory_assignments(
+    fields: &[ParsedField],
+    required_values_source: &proc_macro2::TokenStream,
+) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .map(|f| {
+            let param_name = &f.ident;
+            if let Some(param_default_value) = f.default_tokens.as_ref() {
+                quote! {
+                    #param_name : #param_default_value,
+                }
+            } else if f.builder_attrs.skip {
+                quote! {
+                    #param_name : Default::default(),
+                }
+            } else if f.is_option() && !f.builder_attrs.required {
+                quote! {
+                    #param_name : None,
+                }
+            } else if f.is_option() {
+                quote! {
+                    #param_name : Some(#required_values_source #param_name),
+                }
+            } else {
+                quote! {
+                    #param_name : #required_values_source #param_name,
+                }
+            }
+        })
+        .collect()
 }
 
-#[derive(Clone)]
-struct ParsedField {
-    ident: Ident,
-    parsed_field_type: ParsedFieldType,
-    default_tokens: Option<proc_macro2::TokenStream>,
-    visibility: Visibility,
+fn generate_tuple_struct_functions(
+    fields: &FieldsUnnamed,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> Result<proc_macro2::TokenStream> {
+    let methods_visibility = struct_attrs.methods_visibility();
+    let factory_method_name = struct_attrs.factory_method_name();
+    let inline_attr = struct_attrs.inline_attr();
+
+    let parsed_fields: Vec<(
+        syn::Index,
+        ParsedFieldType,
+        Option<proc_macro2::TokenStream>,
+        &Visibility,
+    )> = fields
+        .unnamed
+        .iter()
+        .enumerate()
+        .map(|(idx, f)| {
+            parse_field_builder_attrs(f)?;
+            // Setter attributes need named setters, so none of them are applied to positional fields
+            if let Some(builder_attr) = f.attrs.iter().find(|a| a.path().is_ident("builder")) {
+                return Err(Error::new_spanned(
+                    builder_attr,
+                    "builder field attributes aren't supported on tuple struct fields",
+                ));
+            }
+            Ok((
+                syn::Index::from(idx),
+                parse_field_type(&f.ty),
+                parse_field_default_attr(f)?,
+                struct_attrs.vis.as_ref().unwrap_or(&f.vis),
+            ))
+        })
+        .collect::<Result<_>>()?;
+
+    let mut generated_new_params: Vec<proc_macro2::TokenStream> = vec![];
+    let mut generated_factory_assignments: Vec<proc_macro2::TokenStream> = vec![];
+    let mut generated_fields_methods: Vec<proc_macro2::TokenStream> = vec![];
+
+    for (field_index, parsed_field_type, default_tokens, field_visibility) in parsed_fields.iter() {
+        let param_name = format_ident!("field_{}", field_index);
+        let with_field_name = format_ident!("with_{}", field_index);
+        let field_type = &parsed_field_type.field_type;
+
+        match parsed_field_type.parsed_type.as_ref() {
+            Some(ParsedType::OptionalType(ga_type_box)) => {
+                let ga_type = &ga_type_box.field_type;
+                let without_field_name = format_ident!("without_{}", field_index);
+                let opt_field_name = format_ident!("opt_{}", field_index);
+
+                generated_factory_assignments.push(if let Some(default_value) = default_tokens {
+                    quote! { #field_index : #default_value, }
+                } else {
+                    quote! { #field_index : None, }
+                });
+
+                generated_fields_methods.push(quote! {
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #with_field_name(self, value : #ga_type) -> Self {
+                        Self {
+                            #field_index : Some(value),
+                            .. self
+                        }
+                    }
+
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #without_field_name(self) -> Self {
+                        Self {
+                            #field_index : None,
+                            .. self
+                        }
+                    }
+
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #opt_field_name(self, value : #field_type) -> Self {
+                        Self {
+                            #field_index : value,
+                            .. self
+                        }
+                    }
+                });
+            }
+            _ => {
+                generated_factory_assignments.push(if let Some(default_value) = default_tokens {
+                    quote! { #field_index : #default_value, }
+                } else {
+                    generated_new_params.push(quote! { #param_name : #field_type, });
+                    quote! { #field_index : #param_name, }
+                });
+
+                generated_fields_methods.push(quote! {
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #with_field_name(self, value : #field_type) -> Self {
+                        Self {
+                            #field_index : value,
+                            .. self
+                        }
+                    }
+                });
+            }
+        }
+    }
+
+    Ok(quote! {
+        #[allow(clippy::too_many_arguments)]
+        #methods_visibility fn #factory_method_name(#(#generated_new_params)*) -> Self {
+            Self {
+                #(#generated_factory_assignments)*
+            }
+        }
+
+        #(#generated_fields_methods)*
+    })
 }
 
-impl ParsedField {
-    fn is_option(&self) -> bool {
-        self.parsed_field_type
-            .parsed_type
-            .as_ref()
-            .filter(|t| t.is_option())
-            .is_some()
+fn generate_default_impl(
+    struct_name: &Ident,
+    fields: &[ParsedField],
+    struct_generics: &Generics,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> proc_macro2::TokenStream {
+    let (required_fields, other_fields): (Vec<ParsedField>, Vec<ParsedField>) =
+        fields.iter().cloned().partition(|f| f.is_required_field());
+
+    // Opt-in, so it doesn't conflict with `#[derive(Default)]` on the struct
+    if !struct_attrs.derive_default {
+        return quote! {};
     }
 
-    fn is_required_field(&self) -> bool {
-        !self.is_option() && self.default_tokens.is_none()
+    let mut generated_factory_assignments = generate_factory_assignments(&other_fields, &quote! {});
+    // Spanned at the field type to report the fields without Default instances
+    generated_factory_assignments.extend(required_fields.iter().map(|f| {
+        let field_name = &f.ident;
+        let field_type = &f.parsed_field_type.field_type;
+        let field_type_span = syn::spanned::Spanned::span(field_type);
+        let default_value = quote_spanned! { field_type_span =>
+            <#field_type as Default>::default()
+        };
+        quote! {
+            #field_name : #default_value,
+        }
+    }));
+
+    let (struct_impl_generics, struct_ty_generics, struct_where_clause) =
+        struct_generics.split_for_impl();
+
+    quote! {
+        #[allow(clippy::needless_update)]
+        impl #struct_impl_generics Default for #struct_name #struct_ty_generics #struct_where_clause {
+            fn default() -> Self {
+                Self {
+                    #(#generated_factory_assignments)*
+                }
+            }
+        }
     }
 }
 
-#[inline]
-fn parse_field_type(field_type: &Type) -> ParsedFieldType {
-    match field_type {
-        Type::Path(ref path) => {
-            let full_type_path: &String = &path
-                .path
-                .segments
-                .iter()
-                .map(|s| s.ident.to_string())
-                .collect::<Vec<String>>()
-                .join("::");
+fn generate_init_struct(
+    struct_name: &Ident,
+    init_struct_name: &Ident,
+    fields: &Vec<ParsedField>,
+    struct_generics: &Generics,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> proc_macro2::TokenStream {
+    let methods_visibility = struct_attrs.methods_visibility();
+    let init_derive = &struct_attrs.init_derive;
+    let required_fields: Vec<ParsedField> = fields
+        .clone()
+        .into_iter()
+        .filter(|f| f.is_required_field())
+        .collect();
 
-            let parsed_type = match full_type_path.as_str() {
-                "String" | "std::string::String" => Some(ParsedType::StringType),
-                "Option" | "std::option::Option" => {
-                    let type_params = &path.path.segments.last().unwrap().arguments;
-                    match type_params {
-                        PathArguments::AngleBracketed(ref params) => params
-                            .args
-                            .first()
-                            .map(|ga| match ga {
-                                GenericArgument::Type(ref ty) => {
-                                    Some(ParsedType::OptionalType(Box::from(parse_field_type(ty))))
-                                }
-                                _ => None,
-                            })
-                            .flatten(),
-                        _ => None,
-                    }
+    let generated_init_fields =
+        generate_init_fields(&required_fields, struct_attrs.init_derives_serde());
+    let generated_init_derive = if init_derive.is_empty() {
+        quote! {}
+    } else {
+        quote! { #[derive(#(#init_derive),*)] }
+    };
+    let generated_non_exhaustive = if struct_attrs.non_exhaustive {
+        quote! { #[non_exhaustive] }
+    } else {
+        quote! {}
+    };
+    let generated_factory_assignments = generate_factory_assignments(fields, &quote! { value. });
+    let generated_validations = generate_validations(&required_fields, &quote! { value. });
+    let factory_method_name = struct_attrs.factory_method_name();
+    let required_field_names: Vec<&Ident> = required_fields.iter().map(|f| &f.ident).collect();
+
+    let init_generics = generate_init_struct_generics(&required_fields, struct_generics);
+    let (_, init_ty_generics, init_where_clause) = init_generics.split_for_impl();
+
+    let (struct_impl_generics, struct_ty_generics, struct_where_clause) =
+        struct_generics.split_for_impl();
+
+    // From<> can't be generated along with TryFrom<> and would bypass the validators
+    let generated_from_impl = if generated_validations.is_empty() {
+        quote! {
+            impl #struct_impl_generics From < #init_struct_name #init_ty_generics > for #struct_name #struct_ty_generics #struct_where_clause {
+                fn from(value: #init_struct_name #init_ty_generics) -> Self {
+                    Self::#factory_method_name(#(value.#required_field_names),*)
                 }
-                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" | "u8" | "u16" | "u32" | "u64"
-                | "u128" | "usize" => Some(ParsedType::ScalarType),
-                _ => None,
-            };
+            }
+        }
+    } else {
+        quote! {
+            #[allow(clippy::needless_update)]
+            impl #struct_impl_generics TryFrom < #init_struct_name #init_ty_generics > for #struct_name #struct_ty_generics #struct_where_clause {
+                type Error = String;
 
-            ParsedFieldType {
-                field_type: field_type.clone(),
-                parsed_type,
-                lifetime: None,
+                fn try_from(value: #init_struct_name #init_ty_generics) -> std::result::Result<Self, Self::Error> {
+                    #(#generated_validations)*
+                    Ok(Self {
+                        #(#generated_factory_assignments)*
+                    })
+                }
             }
         }
-        Type::Reference(ref type_ref) => ParsedFieldType {
-            lifetime: type_ref.lifetime.clone(),
-            field_type: field_type.clone(),
-            parsed_type: None,
-        },
-        _ => ParsedFieldType {
-            field_type: field_type.clone(),
-            parsed_type: None,
-            lifetime: None,
-        },
+    };
+
+    // Required Option<> fields can be reset to None, so there is no infallible conversion back
+    let generated_into_init_impl = if required_fields.iter().any(|f| f.is_option()) {
+        quote! {}
+    } else {
+        let generated_init_assignments: Vec<proc_macro2::TokenStream> = required_fields
+            .iter()
+            .map(|f| {
+                let field_name = &f.ident;
+                quote! {
+                    #field_name : value.#field_name,
+                }
+            })
+            .collect();
+
+        quote! {
+            impl #struct_impl_generics From < #struct_name #struct_ty_generics > for #init_struct_name #init_ty_generics #struct_where_clause {
+                fn from(value: #struct_name #struct_ty_generics) -> Self {
+                    Self {
+                        #(#generated_init_assignments)*
+                    }
+                }
+            }
+        }
+    };
+
+    quote! {
+        #generated_init_derive
+        #generated_non_exhaustive
+        #[allow(dead_code)]
+        #[allow(clippy::needless_update)]
+        #methods_visibility struct #init_struct_name #init_generics #init_where_clause {
+            #(#generated_init_fields)*
+        }
+
+        #generated_from_impl
+
+        #generated_into_init_impl
     }
 }
 
-fn parse_fields(fields: &FieldsNamed) -> Vec<ParsedField> {
-    fields.named.iter().map(parse_field).collect()
-}
+fn generate_tuple_from_impl(
+    struct_name: &Ident,
+    fields: &[ParsedField],
+    struct_generics: &Generics,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> proc_macro2::TokenStream {
+    let factory_method_name = struct_attrs.factory_method_name();
+    let required_fields: Vec<&ParsedField> =
+        fields.iter().filter(|f| f.is_required_field()).collect();
 
-fn parse_field(field: &Field) -> ParsedField {
-    ParsedField {
-        ident: field.ident.as_ref().unwrap().clone(),
-        parsed_field_type: parse_field_type(&field.ty),
-        default_tokens: parse_field_default_attr(field),
-        visibility: field.vis.clone(),
+    // Validators can't be bypassed
+    if required_fields.is_empty()
+        || required_fields
+            .iter()
+            .any(|f| f.builder_attrs.validate.is_some())
+    {
+        return quote! {};
     }
-}
 
-fn generate_fields_functions(fields: &[ParsedField]) -> Vec<proc_macro2::TokenStream> {
-    fields.iter().map(generate_field_functions).collect()
-}
+    let required_fields_idents: Vec<&Ident> = required_fields.iter().map(|f| &f.ident).collect();
+    let required_fields_types: Vec<&Type> = required_fields
+        .iter()
+        .map(|f| f.required_param_type())
+        .collect();
 
-fn generate_field_functions(field: &ParsedField) -> proc_macro2::TokenStream {
-    let field_name = &field.ident;
-    let set_field_name = format_ident!("{}", field_name);
-    let reset_field_name = format_ident!("reset_{}", field_name);
-    let with_field_name = format_ident!("with_{}", field_name);
-    let without_field_name = format_ident!("without_{}", field_name);
-    let opt_field_name = format_ident!("opt_{}", field_name);
-    let mut_opt_field_name = format_ident!("mopt_{}", field_name);
+    let (struct_impl_generics, struct_ty_generics, struct_where_clause) =
+        struct_generics.split_for_impl();
 
-    let field_type = &field.parsed_field_type.field_type;
-    let field_visibility = &field.visibility;
+    if let ([field_ident], [field_type]) = (
+        required_fields_idents.as_slice(),
+        required_fields_types.as_slice(),
+    ) {
+        // Opt-in, so it doesn't conflict with own `From<>` instances for the field type
+        if !struct_attrs.from_field {
+            return quote! {};
+        }
 
-    match field.parsed_field_type.parsed_type.as_ref() {
-        Some(ParsedType::OptionalType(ga_type_box)) => {
-            let parsed_ga_field_type: &ParsedFieldType = &*ga_type_box;
-            let ga_type = &parsed_ga_field_type.field_type;
+        let type_params: Vec<Ident> = struct_generics
+            .type_params()
+            .map(|tp| tp.ident.clone())
+            .collect();
 
-            quote! {
-                #[inline]
-                #field_visibility fn #set_field_name(&mut self, value : #ga_type) -> &mut Self {
-                    self.#field_name = Some(value);
-                    self
-                }
+        // An associated type like `T::Item` might be the struct itself,
+        // so the conversion could conflict with the blanket `From<T> for T`
+        if tokens_contain_projection(field_type.to_token_stream(), &type_params) {
+            return quote! {};
+        }
 
-                #[inline]
-                #field_visibility fn #reset_field_name(&mut self) -> &mut Self {
-                    self.#field_name = None;
-                    self
+        return quote! {
+            impl #struct_impl_generics From<#field_type> for #struct_name #struct_ty_generics #struct_where_clause {
+                fn from(#field_ident: #field_type) -> Self {
+                    #struct_name::#factory_method_name(#field_ident)
                 }
+            }
+        };
+    }
 
-                #[inline]
-                #field_visibility fn #mut_opt_field_name(&mut self, value : #field_type) -> &mut Self {
-                    self.#field_name = value;
-                    self
-                }
+    quote! {
+        impl #struct_impl_generics From<(#(#required_fields_types),*)> for #struct_name #struct_ty_generics #struct_where_clause {
+            fn from((#(#required_fields_idents),*): (#(#required_fields_types),*)) -> Self {
+                #struct_name::#factory_method_name(#(#required_fields_idents),*)
+            }
+        }
+    }
+}
+
+fn generate_init_struct_generics(
+    required_fields: &[ParsedField],
+    struct_generics: &Generics,
+) -> Generics {
+    let is_required_param = |gp: &GenericParam| match gp {
+        GenericParam::Lifetime(ref lt) => required_fields
+            .iter()
+            .any(|f| field_contains_lifetime(f, lt)),
+        GenericParam::Type(ref tp) => required_fields
+            .iter()
+            .any(|f| field_contains_type(&f.parsed_field_type.field_type, tp)),
+        GenericParam::Const(ref cp) => required_fields
+            .iter()
+            .any(|f| field_contains_const(&f.parsed_field_type.field_type, cp)),
+    };
+
+    let params = struct_generics
+        .params
+        .iter()
+        .filter(|gp| is_required_param(gp))
+        .cloned()
+        .collect();
+
+    let excluded_params: Vec<Ident> = struct_generics
+        .params
+        .iter()
+        .filter(|gp| !is_required_param(gp))
+        .map(|gp| match gp {
+            GenericParam::Lifetime(ref lt) => lt.lifetime.ident.clone(),
+            GenericParam::Type(ref tp) => tp.ident.clone(),
+            GenericParam::Const(ref cp) => cp.ident.clone(),
+        })
+        .collect();
+
+    let where_clause = struct_generics
+        .where_clause
+        .as_ref()
+        .map(|where_clause| WhereClause {
+            where_token: where_clause.where_token,
+            predicates: where_clause
+                .predicates
+                .iter()
+                .filter(|predicate| {
+                    !tokens_contain_any_ident(predicate.to_token_stream(), &excluded_params)
+                })
+                .cloned()
+                .collect(),
+        })
+        .filter(|where_clause| !where_clause.predicates.is_empty());
+
+    Generics {
+        lt_token: Some(Default::default()),
+        params,
+        gt_token: Some(Default::default()),
+        where_clause,
+    }
+}
+
+fn tokens_contain_any_ident(tokens: proc_macro2::TokenStream, idents: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ref ident) => idents.contains(ident),
+        proc_macro2::TokenTree::Group(ref group) => {
+            tokens_contain_any_ident(group.stream(), idents)
+        }
+        _ => false,
+    })
+}
+
+fn tokens_contain_projection(tokens: proc_macro2::TokenStream, type_params: &[Ident]) -> bool {
+    let tokens: Vec<proc_macro2::TokenTree> = tokens.into_iter().collect();
+    tokens.iter().enumerate().any(|(idx, token)| match token {
+        proc_macro2::TokenTree::Ident(ref ident) => {
+            ident == "as"
+                || (type_params.contains(ident)
+                    && matches!(tokens.get(idx + 1), Some(proc_macro2::TokenTree::Punct(ref punct)) if punct.as_char() == ':'))
+        }
+        proc_macro2::TokenTree::Group(ref group) => {
+            tokens_contain_projection(group.stream(), type_params)
+        }
+        _ => false,
+    })
+}
 
-                #[inline]
-                #field_visibility fn #with_field_name(self, value : #ga_type) -> Self {
-                    Self {
-                        #field_name : Some(value),
-                        .. self
-                    }
+fn generate_merge_method(
+    fields: &[ParsedField],
+    methods_visibility: &Visibility,
+) -> proc_macro2::TokenStream {
+    let generated_merge_assignments: Vec<proc_macro2::TokenStream> = fields
+        .iter()
+        .map(|f| {
+            let field_name = &f.ident;
+            if f.is_option() {
+                quote! {
+                    #field_name : other.#field_name.or(self.#field_name),
                 }
-
-                #[inline]
-                #field_visibility fn #without_field_name(self) -> Self {
-                    Self {
-                        #field_name : None,
-                        .. self
-                    }
+            } else {
+                quote! {
+                    #field_name : other.#field_name,
                 }
+            }
+        })
+        .collect();
 
-                #[inline]
-                #field_visibility fn #opt_field_name(self, value : #field_type) -> Self {
-                    Self {
-                        #field_name : value,
-                        .. self
-                    }
-                }
+    quote! {
+        #methods_visibility fn merge(self, other: Self) -> Self {
+            Self {
+                #(#generated_merge_assignments)*
             }
         }
-        _ => {
-            quote! {
-                #[inline]
-                #field_visibility fn #set_field_name(&mut self, value : #field_type) -> &mut Self {
-                    self.#field_name = value;
-                    self
-                }
+    }
+}
 
-                #[inline]
-                #field_visibility fn #with_field_name(self, value : #field_type) -> Self {
-                    Self {
-                        #field_name : value,
-                        .. self
-                    }
-                }
+fn generate_reset_all_method(
+    fields: &[ParsedField],
+    methods_visibility: &Visibility,
+) -> proc_macro2::TokenStream {
+    let generated_reset_assignments: Vec<proc_macro2::TokenStream> = fields
+        .iter()
+        .filter_map(|f| {
+            let field_name = &f.ident;
+            if let Some(default_value) = f.default_tokens.as_ref() {
+                Some(quote! {
+                    #field_name : #default_value,
+                })
+            } else if f.is_option() && !f.builder_attrs.required && !f.builder_attrs.skip {
+                Some(quote! {
+                    #field_name : None,
+                })
+            } else {
+                None
+            }
+        })
+        .collect();
+
+    quote! {
+        #[must_use]
+        #methods_visibility fn with_defaults(self) -> Self {
+            Self {
+                #(#generated_reset_assignments)*
+                .. self
             }
         }
     }
 }
 
-fn generate_factory_method(fields: &Vec<ParsedField>) -> proc_macro2::TokenStream {
-    let required_fields: Vec<ParsedField> = fields
-        .clone()
-        .into_iter()
+fn generate_apply_method(struct_attrs: &ParsedStructBuilderAttrs) -> proc_macro2::TokenStream {
+    let methods_visibility = struct_attrs.methods_visibility();
+    let inline_attr = struct_attrs.inline_attr();
+
+    quote! {
+        #[must_use]
+        #inline_attr
+        #methods_visibility fn apply(mut self, f : impl FnOnce(&mut Self)) -> Self {
+            f(&mut self);
+            self
+        }
+    }
+}
+
+fn generate_field_names_method(
+    fields: &[ParsedField],
+    methods_visibility: &Visibility,
+) -> proc_macro2::TokenStream {
+    let field_name_str = |f: &ParsedField| f.ident.to_string().trim_start_matches("r#").to_string();
+    let field_names: Vec<String> = fields.iter().map(field_name_str).collect();
+    let required_field_names: Vec<String> = fields
+        .iter()
         .filter(|f| f.is_required_field())
+        .map(field_name_str)
         .collect();
 
-    let generated_new_params = generate_new_params(&required_fields);
-    let generated_factory_assignments = generate_factory_assignments(fields);
-
     quote! {
-        pub fn new(#(#generated_new_params)*) -> Self {
-            Self {
-                #(#generated_factory_assignments)*
-            }
+        #methods_visibility const REQUIRED_FIELDS: &'static [&'static str] = &[#(#required_field_names),*];
+
+        #methods_visibility fn field_names() -> &'static [&'static str] {
+            &[#(#field_names),*]
         }
     }
 }
 
-fn generate_new_params(fields: &[ParsedField]) -> Vec<proc_macro2::TokenStream> {
-    fields
+fn generate_is_complete_method(
+    fields: &[ParsedField],
+    methods_visibility: &Visibility,
+) -> proc_macro2::TokenStream {
+    let generated_checks: Vec<proc_macro2::TokenStream> = fields
         .iter()
+        .filter(|f| f.is_option())
         .map(|f| {
-            let param_name = &f.ident;
-            let param_type = &f.parsed_field_type.field_type;
-
+            let field_name = &f.ident;
             quote! {
-                #param_name : #param_type,
+                if self.#field_name.is_none() {
+                    return false;
+                }
             }
         })
-        .collect()
+        .collect();
+
+    quote! {
+        #methods_visibility fn is_complete(&self) -> bool {
+            #(#generated_checks)*
+            true
+        }
+    }
 }
 
-fn generate_factory_assignments(fields: &[ParsedField]) -> Vec<proc_macro2::TokenStream> {
-    fields
+fn generate_changed_fields_method(
+    fields: &[ParsedField],
+    methods_visibility: &Visibility,
+) -> proc_macro2::TokenStream {
+    let generated_checks: Vec<proc_macro2::TokenStream> = fields
         .iter()
         .map(|f| {
-            let param_name = &f.ident;
-            if f.default_tokens.is_some() {
-                let param_default_value = f.default_tokens.as_ref().unwrap();
-                quote! {
-                    #param_name : #param_default_value,
-                }
-            } else if f.is_option() {
-                quote! {
-                    #param_name : None,
-                }
-            } else {
-                quote! {
-                    #param_name : #param_name,
+            let field_name = &f.ident;
+            let field_name_str = f.ident.to_string().trim_start_matches("r#").to_string();
+            quote! {
+                if self.#field_name != other.#field_name {
+                    changed.push(#field_name_str);
                 }
             }
         })
-        .collect()
+        .collect();
+
+    quote! {
+        #methods_visibility fn changed_fields(&self, other: &Self) -> Vec<&'static str> {
+            let mut changed = Vec::new();
+            #(#generated_checks)*
+            changed
+        }
+    }
 }
 
-fn generate_init_struct(
-    struct_name: &Ident,
-    fields: &Vec<ParsedField>,
-    struct_generic_params: &Vec<&TypeParam>,
-    struct_generic_params_idents: &Vec<&Ident>,
-    struct_lifetime_params: &Vec<&LifetimeDef>,
-    struct_where_decl: Option<&syn::WhereClause>,
+fn generate_into_init_method(
+    init_struct_name: &Ident,
+    fields: &[ParsedField],
+    struct_generics: &Generics,
+    methods_visibility: &Visibility,
 ) -> proc_macro2::TokenStream {
-    let init_struct_name = format_ident!("{}Init", struct_name);
-
     let required_fields: Vec<ParsedField> = fields
-        .clone()
-        .into_iter()
+        .iter()
         .filter(|f| f.is_required_field())
+        .cloned()
         .collect();
 
-    let generated_init_fields = generate_init_fields(&required_fields);
-    let generated_init_new_params = generate_init_new_params(&required_fields);
+    // Required Option<> fields can be reset to None, so there is no infallible conversion back
+    if required_fields.iter().any(|f| f.is_option()) {
+        return quote! {};
+    }
+
+    let init_generics = generate_init_struct_generics(&required_fields, struct_generics);
+    let (_, init_ty_generics, _) = init_generics.split_for_impl();
 
-    let mut init_fields_generic_params: Vec<&&TypeParam> = required_fields
+    let generated_init_assignments: Vec<proc_macro2::TokenStream> = required_fields
         .iter()
         .map(|f| {
-            struct_generic_params
-                .iter()
-                .find(|gp| field_contains_type(&f.parsed_field_type.field_type, gp))
+            let field_name = &f.ident;
+            quote! {
+                #field_name : self.#field_name,
+            }
         })
-        .flatten()
         .collect();
 
-    init_fields_generic_params.dedup_by_key(|tp| &tp.ident);
+    quote! {
+        #methods_visibility fn into_init(self) -> #init_struct_name #init_ty_generics {
+            #init_struct_name {
+                #(#generated_init_assignments)*
+            }
+        }
+    }
+}
 
-    let init_fields_generic_params_idents: Vec<&Ident> = init_fields_generic_params
+fn generate_build_with_method(
+    init_struct_name: &Ident,
+    fields: &[ParsedField],
+    struct_generics: &Generics,
+    methods_visibility: &Visibility,
+) -> proc_macro2::TokenStream {
+    let required_fields: Vec<ParsedField> = fields
         .iter()
-        .map(|gp| &gp.ident)
+        .filter(|f| f.is_required_field())
+        .cloned()
         .collect();
 
-    let struct_generic_where_decl: proc_macro2::TokenStream = struct_where_decl
-        .as_ref()
-        .map_or(quote! {}, |wh| quote! { #wh });
+    // The init struct is converted with TryFrom<> when there are validators
+    if required_fields
+        .iter()
+        .any(|f| f.builder_attrs.validate.is_some())
+    {
+        return quote! {};
+    }
+
+    let init_generics = generate_init_struct_generics(&required_fields, struct_generics);
+    let (_, init_ty_generics, _) = init_generics.split_for_impl();
+
+    quote! {
+        #methods_visibility fn build_with<F: FnOnce(Self) -> Self>(init: #init_struct_name #init_ty_generics, f: F) -> Self {
+            f(Self::from(init))
+        }
+    }
+}
+
+fn generate_staged_builder(
+    struct_name: &Ident,
+    fields: &[ParsedField],
+    struct_generics: &Generics,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> proc_macro2::TokenStream {
+    let builder_struct_name = format_ident!("{}Builder", struct_name);
+    let methods_visibility = struct_attrs.methods_visibility();
+    let inline_attr = struct_attrs.inline_attr();
+
+    let (struct_impl_generics, struct_ty_generics, struct_where_clause) =
+        struct_generics.split_for_impl();
 
-    let mut init_fields_lifetime_params: Vec<&&LifetimeDef> = required_fields
+    let generated_builder_inits = generate_builder_inits(fields);
+    let generated_builder_fields = generate_builder_fields(fields);
+
+    let generated_builder_setters: Vec<proc_macro2::TokenStream> = fields
         .iter()
         .map(|f| {
-            struct_lifetime_params
-                .iter()
-                .find(|lt| field_contains_lifetime(f, lt))
+            let field_name = &f.ident;
+            let field_visibility = &f.visibility;
+            let with_field_name = format_ident!("with_{}", f.setter_name());
+            let setter_type = match f.parsed_field_type.parsed_type.as_ref() {
+                Some(ParsedType::OptionalType(ga_type_box)) => &ga_type_box.field_type,
+                _ => &f.parsed_field_type.field_type,
+            };
+            let (value_type, value) = generate_setter_value(f, setter_type);
+            let doc_attrs = &f.doc_attrs;
+
+            quote! {
+                #(#doc_attrs)*
+                #[must_use]
+                #inline_attr
+                #field_visibility fn #with_field_name(self, value : #value_type) -> Self {
+                    Self {
+                        #field_name : Some(#value),
+                        .. self
+                    }
+                }
+            }
         })
-        .flatten()
         .collect();
 
-    init_fields_lifetime_params.dedup_by_key(|lt| &lt.lifetime.ident);
-
-    if init_fields_generic_params.is_empty() && init_fields_lifetime_params.is_empty() {
-        let struct_name_with_possible_generics_lt =
-            if struct_generic_params.is_empty() && struct_lifetime_params.is_empty() {
-                quote! {
-                    #struct_name
-                }
-            } else {
-                quote! {
-                   #struct_name<'_>
+    let generated_missing_checks: Vec<proc_macro2::TokenStream> = fields
+        .iter()
+        .filter(|f| f.is_required_field())
+        .map(|f| {
+            let field_name = &f.ident;
+            let field_name_str = field_name.to_string().trim_start_matches("r#").to_string();
+            quote! {
+                if self.#field_name.is_none() {
+                    missing_fields.push(#field_name_str);
                 }
-            };
-
-        quote! {
-            #[allow(dead_code)]
-            #[allow(clippy::needless_update)]
-            pub struct #init_struct_name {
-                #(#generated_init_fields)*
             }
+        })
+        .collect();
 
-            #[allow(clippy::needless_update)]
-            impl From <#init_struct_name> for #struct_name_with_possible_generics_lt {
-                 fn from(value: #init_struct_name) -> Self {
-                    #struct_name::new(
-                        #(#generated_init_new_params)*
-                    )
-                 }
-            }
+    let generated_build_assignments =
+        generate_builder_build_assignments(struct_name, fields, struct_generics);
+
+    let required_fields: Vec<ParsedField> = fields
+        .iter()
+        .filter(|f| f.is_required_field())
+        .cloned()
+        .collect();
+    let generated_validations = generate_validations(&required_fields, &quote! { value. });
+
+    let builder_generics_decl = &struct_generics.params;
+
+    quote! {
+        #[allow(dead_code)]
+        #methods_visibility struct #builder_struct_name < #builder_generics_decl > #struct_where_clause {
+            #(#generated_builder_fields)*
         }
-    } else {
-        quote! {
-            #[allow(dead_code)]
-            #[allow(clippy::needless_update)]
-            pub struct #init_struct_name< #(#init_fields_lifetime_params),* #(#init_fields_generic_params),* > {
-                #(#generated_init_fields)*
+
+        #[allow(dead_code)]
+        #[allow(clippy::needless_update)]
+        impl #struct_impl_generics #builder_struct_name #struct_ty_generics #struct_where_clause {
+            #(#generated_builder_setters)*
+
+            #methods_visibility fn build(self) -> std::result::Result<#struct_name #struct_ty_generics, String> {
+                let mut missing_fields: Vec<&'static str> = Vec::new();
+                #(#generated_missing_checks)*
+                if !missing_fields.is_empty() {
+                    return Err(format!("missing required fields: {}", missing_fields.join(", ")));
+                }
+
+                let value = #struct_name {
+                    #(#generated_build_assignments)*
+                };
+                #(#generated_validations)*
+                Ok(value)
             }
+        }
 
-            #[allow(clippy::needless_update)]
-            impl < #(#struct_lifetime_params),* #(#struct_generic_params),* > From < #init_struct_name< #(#init_fields_lifetime_params),* #(#init_fields_generic_params_idents),* > > for #struct_name< #(#struct_lifetime_params),* #(#struct_generic_params_idents),* > #struct_generic_where_decl {
-                  fn from(value: #init_struct_name< #(#init_fields_lifetime_params),* #(#init_fields_generic_params_idents),*> ) -> Self {
-                    #struct_name::new(
-                        #(#generated_init_new_params)*
-                    )
-                 }
+        #[allow(dead_code)]
+        impl #struct_impl_generics #struct_name #struct_ty_generics #struct_where_clause {
+            #methods_visibility fn builder() -> #builder_struct_name #struct_ty_generics {
+                #builder_struct_name {
+                    #(#generated_builder_inits)*
+                }
             }
         }
     }
 }
 
-fn generate_init_fields(fields: &Vec<ParsedField>) -> Vec<proc_macro2::TokenStream> {
+fn generate_builder_inits(fields: &[ParsedField]) -> Vec<proc_macro2::TokenStream> {
     fields
         .iter()
         .map(|f| {
-            let param_name = &f.ident;
-            let param_type = &f.parsed_field_type.field_type;
-
+            let field_name = &f.ident;
             quote! {
-                pub #param_name : #param_type,
+                #field_name : None,
             }
         })
         .collect()
 }
 
-fn generate_init_new_params(fields: &Vec<ParsedField>) -> Vec<proc_macro2::TokenStream> {
+fn generate_builder_fields(fields: &[ParsedField]) -> Vec<proc_macro2::TokenStream> {
     fields
         .iter()
         .map(|f| {
-            let param_name = &f.ident;
-            quote! {
-                value.#param_name,
+            let field_name = &f.ident;
+            let field_type = &f.parsed_field_type.field_type;
+            if f.is_option() {
+                quote! {
+                    #field_name : #field_type,
+                }
+            } else {
+                quote! {
+                    #field_name : Option<#field_type>,
+                }
             }
         })
         .collect()
 }
 
-fn parse_field_default_attr(field: &Field) -> Option<proc_macro2::TokenStream> {
-    field
-        .attrs
+fn generate_builder_build_assignments(
+    struct_name: &Ident,
+    fields: &[ParsedField],
+    struct_generics: &Generics,
+) -> Vec<proc_macro2::TokenStream> {
+    let (_, struct_ty_generics, _) = struct_generics.split_for_impl();
+
+    fields
         .iter()
-        .find(|a| match a.style {
-            AttrStyle::Outer => a
-                .path
-                .segments
-                .first()
-                .iter()
-                .any(|s| s.ident.eq("default")),
-            _ => false,
-        })
-        .and_then(|a| {
-            let attr_tokens: &Vec<proc_macro2::TokenTree> = &a.tokens.clone().into_iter().collect();
-            if attr_tokens.len() > 1 {
-                match attr_tokens.last().unwrap() {
-                    proc_macro2::TokenTree::Literal(lit) => {
-                        let lit_str = format!("{}", lit);
-                        let lit_unquoted_str = lit_str.index(1..lit_str.len() - 1);
-                        let lit_stream: proc_macro2::TokenStream =
-                            syn::parse_str(lit_unquoted_str).unwrap();
-                        Some(quote! {
-                            #lit_stream
-                        })
+        .map(|f| {
+            let field_name = &f.ident;
+            if let Some(param_default_value) = f.default_tokens.as_ref() {
+                // Self is the builder here, so associated consts are referenced with the struct type
+                let param_default_value = match f.builder_attrs.default_const {
+                    Some(ref default_const) => {
+                        quote! { <#struct_name #struct_ty_generics>::#default_const }
                     }
-                    _ => None,
+                    None => param_default_value.clone(),
+                };
+                if f.is_option() {
+                    quote! {
+                        #field_name : self.#field_name.or_else(|| #param_default_value),
+                    }
+                } else {
+                    quote! {
+                        #field_name : self.#field_name.unwrap_or_else(|| #param_default_value),
+                    }
+                }
+            } else if f.builder_attrs.skip {
+                quote! {
+                    #field_name : self.#field_name.unwrap_or_default(),
+                }
+            } else if f.is_option() {
+                quote! {
+                    #field_name : self.#field_name,
                 }
             } else {
-                None
+                quote! {
+                    #field_name : self.#field_name.unwrap(),
+                }
+            }
+        })
+        .collect()
+}
+
+fn generate_init_fields(
+    fields: &Vec<ParsedField>,
+    with_serde_attrs: bool,
+) -> Vec<proc_macro2::TokenStream> {
+    fields
+        .iter()
+        .map(|f| {
+            let param_name = &f.ident;
+            let param_type = f.required_param_type();
+            // serde attributes are valid only along with the serde derives
+            let serde_attrs: &[Attribute] = if with_serde_attrs {
+                &f.serde_attrs
+            } else {
+                &[]
+            };
+
+            quote! {
+                #(#serde_attrs)*
+                pub #param_name : #param_type,
             }
         })
+        .collect()
+}
+
+fn parse_field_default_attr(field: &Field) -> Result<Option<proc_macro2::TokenStream>> {
+    let mut default_attrs = field.attrs.iter().filter(|a| match a.style {
+        AttrStyle::Outer => a.path().is_ident("default"),
+        _ => false,
+    });
+    let default_attr = default_attrs.next();
+
+    if let Some(duplicate_attr) = default_attrs.next() {
+        return Err(Error::new_spanned(
+            duplicate_attr,
+            "duplicate default attribute, only one is allowed per field",
+        ));
+    }
+
+    match default_attr.map(|a| &a.meta) {
+        Some(Meta::NameValue(ref name_value)) => match name_value.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Str(ref lit_str),
+                ..
+            }) => {
+                let default_expr: Expr = syn::parse_str(&lit_str.value()).map_err(|err| {
+                    Error::new_spanned(lit_str, format!("invalid default expression: {}", err))
+                })?;
+                let field_type = &field.ty;
+                // Annotating the expression with the field type allows defaults like `"...".into()`
+                Ok(Some(quote! {
+                    ::core::convert::identity::<#field_type>(#default_expr)
+                }))
+            }
+            _ => Err(Error::new_spanned(
+                field,
+                "default value must be a string literal, e.g. #[default = \"10\"]",
+            )),
+        },
+        Some(Meta::Path(_)) => Ok(Some(quote! {
+            Default::default()
+        })),
+        Some(Meta::List(_)) => Err(Error::new_spanned(
+            field,
+            "unsupported default attribute format, use #[default] or #[default = \"...\"]",
+        )),
+        None => Ok(None),
+    }
 }
 
 fn field_contains_type(field_type: &Type, tp: &TypeParam) -> bool {
@@ -587,7 +3682,35 @@ fn field_contains_type(field_type: &Type, tp: &TypeParam) -> bool {
     }
 }
 
-fn field_contains_lifetime(field: &ParsedField, lt: &LifetimeDef) -> bool {
+fn field_contains_const(field_type: &Type, cp: &ConstParam) -> bool {
+    match field_type {
+        Type::Array(ref type_array) => {
+            matches!(type_array.len, Expr::Path(ref len_path) if len_path.path.is_ident(&cp.ident))
+                || field_contains_const(&type_array.elem, cp)
+        }
+        // A const argument without braces (`Matrix<T, N>`) is parsed as a type path
+        Type::Path(ref path) => {
+            path.path.is_ident(&cp.ident)
+                || path.path.segments.iter().any(|s| match s.arguments {
+                    PathArguments::AngleBracketed(ref params) => {
+                        params.args.iter().any(|ga| match ga {
+                            GenericArgument::Type(ref ty) => field_contains_const(ty, cp),
+                            GenericArgument::Const(ref expr) => tokens_contain_any_ident(
+                                expr.to_token_stream(),
+                                std::slice::from_ref(&cp.ident),
+                            ),
+                            _ => false,
+                        })
+                    }
+                    _ => false,
+                })
+        }
+        Type::Reference(ref type_ref) => field_contains_const(&type_ref.elem, cp),
+        _ => false,
+    }
+}
+
+fn field_contains_lifetime(field: &ParsedField, lt: &LifetimeParam) -> bool {
     field
         .parsed_field_type
         .lifetime
@@ -597,7 +3720,7 @@ fn field_contains_lifetime(field: &ParsedField, lt: &LifetimeDef) -> bool {
         || field_contains_lifetime_type(&field.parsed_field_type.field_type, lt)
 }
 
-fn field_contains_lifetime_type(field_type: &Type, lt: &LifetimeDef) -> bool {
+fn field_contains_lifetime_type(field_type: &Type, lt: &LifetimeParam) -> bool {
     match field_type {
         Type::Path(ref path) => path.path.segments.iter().any(|s| match s.arguments {
             PathArguments::AngleBracketed(ref params) => params.args.iter().any(|ga| match ga {
@@ -607,6 +3730,28 @@ fn field_contains_lifetime_type(field_type: &Type, lt: &LifetimeDef) -> bool {
             }),
             _ => false,
         }),
+        Type::Reference(ref type_ref) => {
+            type_ref
+                .lifetime
+                .as_ref()
+                .filter(|flt| lt.lifetime.eq(flt))
+                .is_some()
+                || field_contains_lifetime_type(&type_ref.elem, lt)
+        }
         _ => false,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn field_default_attr_ignores_other_paths() {
+        let field: Field = parse_quote! {
+            #[default::something]
+            pub field: i32
+        };
+        assert!(matches!(parse_field_default_attr(&field), Ok(None)));
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{
+    generate_builder_build_assignments, generate_builder_fields, generate_builder_inits,
+    generate_setter_value, generate_validations, ParsedField, ParsedStructBuilderAttrs, ParsedType,
+};
+use quote::{format_ident, quote, ToTokens};
+use syn::{parse_quote, GenericParam, Generics, Ident};
+
+pub(crate) fn generate_typestate_builder(
+    struct_name: &Ident,
+    fields: &[ParsedField],
+    struct_generics: &Generics,
+    struct_attrs: &ParsedStructBuilderAttrs,
+) -> proc_macro2::TokenStream {
+    let methods_visibility = struct_attrs.methods_visibility();
+    let inline_attr = struct_attrs.inline_attr();
+    let builder_struct_name = format_ident!("{}TypestateBuilder", struct_name);
+    let set_state_name = format_ident!("{}FieldSet", struct_name);
+    let unset_state_name = format_ident!("{}FieldUnset", struct_name);
+
+    let (struct_impl_generics, struct_ty_generics, struct_where_clause) =
+        struct_generics.split_for_impl();
+
+    let required_fields: Vec<&ParsedField> =
+        fields.iter().filter(|f| f.is_required_field()).collect();
+
+    // Every required field has its own state parameter, which is either set or unset
+    let state_params: Vec<Ident> = (0..required_fields.len())
+        .map(|idx| format_ident!("__State{}", idx))
+        .collect();
+
+    let struct_args: Vec<proc_macro2::TokenStream> = struct_generics
+        .params
+        .iter()
+        .map(|gp| match gp {
+            GenericParam::Lifetime(ref lt) => lt.lifetime.to_token_stream(),
+            GenericParam::Type(ref tp) => tp.ident.to_token_stream(),
+            GenericParam::Const(ref cp) => cp.ident.to_token_stream(),
+        })
+        .collect();
+
+    let builder_generics = with_state_params(struct_generics, &state_params);
+    let (builder_impl_generics, builder_ty_generics, _) = builder_generics.split_for_impl();
+
+    let builder_type = |states: &[proc_macro2::TokenStream]| {
+        quote! { #builder_struct_name < #(#struct_args,)* #(#states),* > }
+    };
+
+    let unset_states: Vec<proc_macro2::TokenStream> = required_fields
+        .iter()
+        .map(|_| quote! { #unset_state_name })
+        .collect();
+    let set_states: Vec<proc_macro2::TokenStream> = required_fields
+        .iter()
+        .map(|_| quote! { #set_state_name })
+        .collect();
+    let unset_builder_type = builder_type(&unset_states);
+    let set_builder_type = builder_type(&set_states);
+
+    let generated_builder_inits = generate_builder_inits(fields);
+    let generated_builder_fields = generate_builder_fields(fields);
+    let generated_build_assignments =
+        generate_builder_build_assignments(struct_name, fields, struct_generics);
+
+    // All required fields are set here, but validators can still reject them like in `try_new`
+    let generated_validations = generate_validations(
+        &required_fields.iter().copied().cloned().collect::<Vec<_>>(),
+        &quote! { value. },
+    );
+    let generated_build_method = if generated_validations.is_empty() {
+        quote! {
+            #methods_visibility fn build(self) -> #struct_name #struct_ty_generics {
+                #struct_name {
+                    #(#generated_build_assignments)*
+                }
+            }
+        }
+    } else {
+        quote! {
+            #methods_visibility fn build(self) -> std::result::Result<#struct_name #struct_ty_generics, String> {
+                let value = #struct_name {
+                    #(#generated_build_assignments)*
+                };
+                #(#generated_validations)*
+                Ok(value)
+            }
+        }
+    };
+
+    let generated_required_setters: Vec<proc_macro2::TokenStream> = required_fields
+        .iter()
+        .enumerate()
+        .map(|(field_idx, f)| {
+            let field_name = &f.ident;
+            let field_visibility = &f.visibility;
+            let with_field_name = format_ident!("with_{}", f.setter_name());
+            let (value_type, value) = generate_setter_value(f, f.required_param_type());
+            let doc_attrs = &f.doc_attrs;
+
+            let other_state_params: Vec<Ident> = state_params
+                .iter()
+                .enumerate()
+                .filter(|(idx, _)| *idx != field_idx)
+                .map(|(_, state_param)| state_param.clone())
+                .collect();
+            let setter_generics = with_state_params(struct_generics, &other_state_params);
+            let (setter_impl_generics, _, _) = setter_generics.split_for_impl();
+
+            let states_with = |state_name: &Ident| -> Vec<proc_macro2::TokenStream> {
+                state_params
+                    .iter()
+                    .enumerate()
+                    .map(|(idx, state_param)| {
+                        if idx == field_idx {
+                            quote! { #state_name }
+                        } else {
+                            quote! { #state_param }
+                        }
+                    })
+                    .collect()
+            };
+            let from_builder_type = builder_type(&states_with(&unset_state_name));
+            let to_builder_type = builder_type(&states_with(&set_state_name));
+
+            let generated_moved_fields: Vec<proc_macro2::TokenStream> = fields
+                .iter()
+                .filter(|other| other.ident != f.ident)
+                .map(|other| {
+                    let other_field_name = &other.ident;
+                    quote! {
+                        #other_field_name : self.#other_field_name,
+                    }
+                })
+                .collect();
+
+            quote! {
+                #[allow(dead_code)]
+                impl #setter_impl_generics #from_builder_type #struct_where_clause {
+                    #(#doc_attrs)*
+                    #[must_use]
+                    #inline_attr
+                    #field_visibility fn #with_field_name(self, value : #value_type) -> #to_builder_type {
+                        #builder_struct_name {
+                            #field_name : Some(#value),
+                            #(#generated_moved_fields)*
+                            _state : std::marker::PhantomData,
+                        }
+                    }
+                }
+            }
+        })
+        .collect();
+
+    let generated_optional_setters: Vec<proc_macro2::TokenStream> = fields
+        .iter()
+        .filter(|f| !f.is_required_field() && !f.builder_attrs.skip)
+        .map(|f| {
+            let field_name = &f.ident;
+            let field_visibility = &f.visibility;
+            let with_field_name = format_ident!("with_{}", f.setter_name());
+            let setter_type = match f.parsed_field_type.parsed_type.as_ref() {
+                Some(ParsedType::OptionalType(ga_type_box)) => &ga_type_box.field_type,
+                _ => &f.parsed_field_type.field_type,
+            };
+            let (value_type, value) = generate_setter_value(f, setter_type);
+            let doc_attrs = &f.doc_attrs;
+
+            quote! {
+                #(#doc_attrs)*
+                #[must_use]
+                #inline_attr
+                #field_visibility fn #with_field_name(self, value : #value_type) -> Self {
+                    Self {
+                        #field_name : Some(#value),
+                        .. self
+                    }
+                }
+            }
+        })
+        .collect();
+
+    let builder_generics_decl = &builder_generics.params;
+
+    quote! {
+        #[allow(dead_code)]
+        #methods_visibility struct #set_state_name;
+
+        #[allow(dead_code)]
+        #methods_visibility struct #unset_state_name;
+
+        #[allow(dead_code)]
+        #methods_visibility struct #builder_struct_name < #builder_generics_decl > #struct_where_clause {
+            #(#generated_builder_fields)*
+            _state : std::marker::PhantomData<(#(#state_params,)*)>,
+        }
+
+        #[allow(dead_code)]
+        #[allow(clippy::needless_update)]
+        impl #builder_impl_generics #builder_struct_name #builder_ty_generics #struct_where_clause {
+            #(#generated_optional_setters)*
+        }
+
+        #(#generated_required_setters)*
+
+        #[allow(dead_code)]
+        impl #struct_impl_generics #set_builder_type #struct_where_clause {
+            #generated_build_method
+        }
+
+        #[allow(dead_code)]
+        impl #struct_impl_generics #struct_name #struct_ty_generics #struct_where_clause {
+            #methods_visibility fn typestate_builder() -> #unset_builder_type {
+                #builder_struct_name {
+                    #(#generated_builder_inits)*
+                    _state : std::marker::PhantomData,
+                }
+            }
+        }
+    }
+}
+
+fn with_state_params(struct_generics: &Generics, state_params: &[Ident]) -> Generics {
+    let mut generics = struct_generics.clone();
+    // Defaults of the struct params would be followed by the state params, which isn't allowed
+    for param in generics.params.iter_mut() {
+        match param {
+            GenericParam::Type(ref mut tp) => {
+                tp.eq_token = None;
+                tp.default = None;
+            }
+            GenericParam::Const(ref mut cp) => {
+                cp.eq_token = None;
+                cp.default = None;
+            }
+            GenericParam::Lifetime(_) => {}
+        }
+    }
+    generics.params.extend(
+        state_params
+            .iter()
+            .map(|state_param| GenericParam::Type(parse_quote! { #state_param })),
+    );
+    generics
+}
@@ -1,7 +1,17 @@
+#[cfg(test)]
+extern crate alloc;
+
 #[cfg(test)]
 mod tests {
 
     use rsb_derive::Builder;
+    use std::borrow::Cow;
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+    use std::num::NonZeroU32;
+    use std::path::PathBuf;
+    use std::rc::Rc;
+    use std::sync::Arc;
+    use std::time::Duration;
 
     #[derive(Debug, Clone, PartialEq, Builder)]
     struct SimpleStrValueStruct {
@@ -66,154 +76,2687 @@ mod tests {
         pub opt_field: Option<&'a str>,
     }
 
-    #[test]
-    fn new_str_value_struct() {
-        let s1: SimpleStrValueStruct = SimpleStrValueStruct::new("hey".into(), 0);
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithFloats {
+        pub req_field1: f64,
+        pub opt_field1: Option<f32>,
+    }
 
-        assert_eq!(s1.req_field1, String::from("hey"));
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithFlags {
+        pub req_flag1: bool,
+        #[default = "true"]
+        pub req_flag2: bool,
+        pub opt_flag1: Option<bool>,
     }
 
-    #[test]
-    fn fill_str_value_struct() {
-        let s1 = SimpleStrValueStruct {
-            req_field1: "hey".into(),
-            req_field2: 0,
-            opt_field1: None,
-            opt_field2: None,
-        }
-        .opt_field1("hey".into())
-        .clone();
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithInto {
+        #[builder(into)]
+        pub req_field1: String,
+        pub req_field2: i32,
+        #[builder(into)]
+        pub opt_field1: Option<String>,
+    }
 
-        assert_eq!(s1.opt_field1, Some("hey".into()));
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(derive_default)]
+    struct StructWithAllOptional<T> {
+        #[default = "10"]
+        pub def_field1: i32,
+        pub opt_field1: Option<String>,
+        #[default = "Some(11)"]
+        pub opt_field2: Option<i32>,
+        pub opt_gen_field1: Option<T>,
+    }
 
-        let s1c = SimpleStrValueStruct {
-            req_field1: "hey".into(),
-            req_field2: 0,
-            opt_field1: None,
-            opt_field2: None,
+    fn validate_non_empty(value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            Err("value must not be empty".into())
+        } else {
+            Ok(())
         }
-        .with_opt_field1("hey2".into());
-        assert_eq!(s1c.opt_field1, Some("hey2".into()));
+    }
 
-        assert_eq!(s1c.without_opt_field1().opt_field1, None);
+    fn validate_positive(value: &i32) -> Result<(), String> {
+        if *value > 0 {
+            Ok(())
+        } else {
+            Err(format!("{} is not positive", value))
+        }
     }
 
-    #[test]
-    fn into_str_value_struct() {
-        let s1: SimpleStrValueStruct = SimpleStrValueStructInit {
-            req_field1: "hey".into(),
-            req_field2: 0,
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithValidation {
+        #[builder(validate = "validate_non_empty")]
+        pub req_field1: String,
+        #[builder(validate = "validate_positive")]
+        pub req_field2: i32,
+        pub opt_field1: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(staged)]
+    struct StructWithStagedValidation {
+        #[builder(validate = "validate_non_empty")]
+        pub r#type: String,
+        #[builder(validate = "validate_positive")]
+        pub req_field2: i32,
+        pub opt_field1: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct TupleStruct(String, Option<i32>);
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct GenericTupleStruct<T>(T, #[default = "10"] i32, Option<T>);
+
+    mod getters {
+        use rsb_derive::Builder;
+
+        #[derive(Debug, Clone, PartialEq, Builder)]
+        pub struct StructWithGetters {
+            #[builder(getter)]
+            req_field1: String,
+            req_field2: i32,
+            #[builder(getter)]
+            pub opt_field1: Option<String>,
         }
-        .into();
+    }
 
-        let s11 = s1.clone().with_opt_field1("hey".into()).with_req_field2(10);
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithNonAsciiDefault {
+        pub req_field1: String,
+        #[default = "'é'"]
+        pub req_field2: char,
+        #[default = "Some('ü')"]
+        pub opt_field1: Option<char>,
+    }
 
-        assert_eq!(s1.req_field1, String::from("hey"));
-        assert_eq!(s11.req_field1, String::from("hey"));
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithStringDefaults {
+        pub req_field1: i32,
+        #[default = "\"hey\".to_string()"]
+        pub def_field1: String,
+        #[default = r#"String::from("hey")"#]
+        pub def_field2: String,
+        #[default = r##"Some(r#"{"k":1}"#.to_string())"##]
+        pub opt_field1: Option<String>,
     }
 
-    #[test]
-    fn all_together_test() {
-        let s1: SimpleStrValueStruct = SimpleStrValueStruct::from(SimpleStrValueStructInit {
-            req_field1: "hey".into(),
-            req_field2: 0,
-        })
-        .with_opt_field1("hey".into())
-        .with_opt_field2(10);
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithVec {
+        pub req_field1: String,
+        #[default = "vec![]"]
+        pub vec_field1: Vec<String>,
+        pub vec_field2: std::vec::Vec<i32>,
+    }
 
-        assert_eq!(s1.req_field1, String::from("hey"));
-        assert_eq!(s1.opt_field1, Some(String::from("hey")));
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithMaps<K: Ord, V> {
+        #[default = "HashMap::new()"]
+        pub map_field1: HashMap<String, i32>,
+        #[default = "BTreeMap::new()"]
+        pub map_field2: BTreeMap<K, V>,
     }
 
-    #[test]
-    fn all_together_mutable_test() {
-        let mut s1: SimpleStrValueStruct = SimpleStrValueStruct::from(SimpleStrValueStructInit {
-            req_field1: "hey".into(),
-            req_field2: 0,
-        });
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithLifetimeAndGenerics<'a, 'b: 'a, T> {
+        pub req_field1: &'a str,
+        pub req_field2: T,
+        pub req_field3: &'b str,
+        pub opt_field1: Option<&'a str>,
+        pub opt_field2: Option<T>,
+    }
 
-        s1.opt_field1("hey".into())
-            .opt_field2(10)
-            .reset_opt_field2();
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithConstGenerics<const N: usize, const M: usize> {
+        pub req_field1: [u8; N],
+        pub req_field2: Vec<[i32; N]>,
+        pub opt_field1: Option<[u8; M]>,
+    }
 
-        assert_eq!(s1.req_field1, String::from("hey"));
-        assert_eq!(s1.opt_field1, Some(String::from("hey")));
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithRename {
+        #[builder(rename = "value")]
+        pub v: i32,
+        #[builder(rename = "label")]
+        pub opt_l: Option<String>,
     }
 
-    #[test]
-    fn generic_struct_test() {
-        let g1: GenericValueStruct<String, i64> =
-            GenericValueStruct::from(GenericValueStructInit {
-                gen_field1: "hey".into(),
-                gen_field2: "hey2".into(),
-            })
-            .with_opt_gen_field1("hey".into());
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithSkip {
+        pub req_field1: String,
+        #[builder(skip)]
+        pub skip_field1: Vec<String>,
+        #[builder(skip)]
+        pub skip_field2: u64,
+        pub opt_field1: Option<i32>,
+    }
 
-        assert_eq!(g1.gen_field1, String::from("hey"));
-        assert_eq!(g1.opt_gen_field1, Some(String::from("hey")));
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithBareDefault {
+        pub req_field1: i32,
+        #[default]
+        pub def_field1: String,
+        #[default]
+        pub def_field2: Vec<i32>,
+        #[default]
+        pub opt_field1: Option<i32>,
     }
 
-    #[test]
-    fn generic_struct_with_bounds_test() {
-        let g1: GenericValueStructWithBounds<i64> =
-            GenericValueStructWithBounds::from(GenericValueStructWithBoundsInit { gen_field1: 17 })
-                .with_opt_gen_field1(37);
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(staged)]
+    struct StructWithStagedBuilder<T> {
+        pub req_field1: String,
+        pub req_field2: T,
+        #[default = "10"]
+        pub def_field1: i32,
+        pub opt_field1: Option<String>,
+    }
 
-        assert_eq!(g1.gen_field1, 17);
-        assert_eq!(g1.opt_gen_field1, Some(37));
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithStrSlices<'a, 'b> {
+        pub req_field1: &'a str,
+        pub req_field2: &'b str,
+        #[default = "\"default\""]
+        pub def_field1: &'static str,
+        pub opt_field1: Option<&'b str>,
     }
 
-    #[test]
-    fn generic_struct_with_bounds_where_test() {
-        let g1: GenericValueStructWithBoundsWhere<i64> =
-            GenericValueStructWithBoundsWhere::from(GenericValueStructWithBoundsWhereInit {
-                gen_field1: 17,
-            })
-            .with_opt_gen_field1(37);
+    #[allow(clippy::box_collection)]
+    mod boxes {
+        use rsb_derive::Builder;
 
-        assert_eq!(g1.gen_field1, 17);
-        assert_eq!(g1.opt_gen_field1, Some(37));
+        #[derive(Debug, Clone, PartialEq, Builder)]
+        pub struct StructWithBox {
+            pub req_field1: Box<String>,
+            #[default = "Box::new(10)"]
+            pub def_field1: Box<i32>,
+        }
     }
 
-    #[test]
-    fn struct_with_defaults_test() {
-        let sd1 = StructWithDefault::from(StructWithDefaultInit {
-            req_field1: "test".into(),
-        });
+    #[derive(Builder)]
+    struct StructWithUnsizedBoxes {
+        pub req_field1: Box<dyn Fn() -> i32>,
+        pub opt_field1: Option<Box<[u8]>>,
+        pub opt_field2: Option<Box<str>>,
+    }
 
-        assert_eq!(sd1.req_field2, 10);
-        assert_eq!(sd1.opt_field2, Some(11));
+    #[derive(Debug, Clone, Builder)]
+    struct StructWithUnsizedSharedPointers {
+        pub req_field1: Arc<str>,
+        pub opt_field1: Option<std::rc::Rc<str>>,
+        pub opt_field2: Option<Arc<dyn std::fmt::Debug>>,
     }
 
-    #[test]
-    fn opt_setter_struct() {
-        let s1: SimpleStrValueStruct = SimpleStrValueStructInit {
-            req_field1: "hey".into(),
-            req_field2: 0,
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(merge)]
+    struct StructWithMerge {
+        pub req_field1: String,
+        pub req_field2: i32,
+        pub opt_field1: Option<String>,
+        pub opt_field2: Option<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithShadowedFieldDefaults {
+        pub i: u32,
+        #[default = "(0..3u32).map(|i| i * 2).sum()"]
+        pub sum_field: u32,
+        #[default = "{ let i = 2; i * 3 }"]
+        pub block_field: u32,
+        pub name: Option<String>,
+        #[default = "match Some(1u32) { Some(name) => name, None => 0 }"]
+        pub match_field: u32,
+        #[default = "if let Some(i) = Some(4u32) { i } else { 0 }"]
+        pub if_let_field: u32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(with_defaults)]
+    struct StructWithResetDefaults {
+        pub req_field1: String,
+        #[default = "10"]
+        pub req_field2: i32,
+        pub opt_field1: Option<String>,
+        #[default = "Some(11)"]
+        pub opt_field2: Option<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(apply)]
+    struct StructWithApply {
+        pub req_field1: String,
+        pub req_field2: i32,
+        pub opt_field1: Option<String>,
+        pub opt_field2: Option<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithOwnMethods {
+        pub req_field1: String,
+        pub opt_field1: Option<String>,
+    }
+
+    impl StructWithOwnMethods {
+        fn merge(self, other: Self) -> Self {
+            Self {
+                req_field1: format!("{}{}", self.req_field1, other.req_field1),
+                ..self
+            }
         }
-        .into();
 
-        let s11 = s1.clone().opt_opt_field1(Some("hey".into()));
+        fn apply(self, f: impl FnOnce(Self) -> Self) -> Self {
+            f(self)
+        }
 
-        assert_eq!(s11.opt_field1, Some(String::from("hey")));
+        fn with_defaults(self) -> Self {
+            Self::new("default".into())
+        }
     }
 
-    #[test]
-    fn different_access_struct() {
-        let s1 = StructWithDifferentAccess::new("hey".into(), 0)
-            .opt_field1("hey".into())
-            .req_field2(0)
-            .clone();
+    impl From<String> for StructWithOwnMethods {
+        fn from(value: String) -> Self {
+            Self::new(value.to_uppercase())
+        }
+    }
 
-        assert_eq!(s1.opt_field1, Some("hey".into()));
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithSharedPointers {
+        pub req_field1: Arc<String>,
+        pub req_field2: std::rc::Rc<Vec<i32>>,
+        pub opt_field1: Option<Arc<String>>,
     }
 
-    #[test]
-    fn struct_with_lifetimes() {
-        let s1 = StructWithLifetime::new("hey".into())
-            .opt_field("hey".into())
-            .clone();
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithOptionalWrappers {
+        pub opt_field1: Option<Vec<String>>,
+        pub opt_field2: Option<Box<i32>>,
+        pub opt_field3: Option<HashMap<String, i32>>,
+    }
 
-        assert_eq!(s1.opt_field, Some("hey".into()));
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(init_name = "CustomInitArgs")]
+    struct StructWithCustomInitName {
+        pub req_field1: String,
+        pub opt_field1: Option<i32>,
+    }
+
+    mod crate_visible {
+        use rsb_derive::Builder;
+
+        #[derive(Debug, Clone, PartialEq, Builder)]
+        #[builder(vis = "pub(crate)")]
+        pub struct StructWithCrateSetters {
+            pub req_field1: String,
+            pub opt_field1: Option<i32>,
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(const_new)]
+    struct StructWithConstNew {
+        pub req_field1: i32,
+        pub req_field2: &'static str,
+        pub opt_field1: Option<i32>,
+    }
+
+    const CONST_STRUCT: StructWithConstNew = StructWithConstNew::new(10, "hey");
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithNonZero {
+        pub req_field1: NonZeroU32,
+        pub req_field2: std::num::NonZeroI64,
+        pub opt_field1: Option<NonZeroU32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(staged)]
+    struct StructWithRequiredOption {
+        pub req_field1: i32,
+        #[builder(required)]
+        pub opt_field1: Option<String>,
+        pub opt_field2: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(complete_when_all_set)]
+    struct StructWithCompleteness {
+        pub req_field1: String,
+        pub opt_field1: Option<String>,
+        pub opt_field2: Option<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(staged)]
+    struct StructWithDocs {
+        /// Required field documentation
+        pub req_field1: String,
+        /// Optional field documentation
+        /// spanning multiple lines
+        pub opt_field1: Option<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(from_field)]
+    struct GenericStructWithDefault<T: Default> {
+        pub req_field1: String,
+        #[default = "T::default()"]
+        pub def_field1: T,
+        #[default = "Vec::<T>::new()"]
+        pub def_field2: Vec<T>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(derive_default)]
+    struct GenericStructWithOnlyDefaults<T: Default> {
+        #[default = "T::default()"]
+        pub def_field1: T,
+        pub opt_field1: Option<T>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithCorePaths {
+        pub req_field1: alloc::string::String,
+        pub opt_field1: core::option::Option<i32>,
+        pub opt_field2: ::core::option::Option<alloc::vec::Vec<i32>>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(setter_prefix = "set_")]
+    struct StructWithSetterPrefix {
+        pub req_field1: String,
+        pub opt_field1: Option<i32>,
+    }
+
+    impl StructWithSetterPrefix {
+        fn req_field1(&self) -> &str {
+            &self.req_field1
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Status {
+        Idle,
+        Running(u32),
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithEnums {
+        pub req_field1: Status,
+        #[default = "Status::Idle"]
+        pub def_field1: Status,
+        pub opt_field1: Option<Status>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithInterleavedFields {
+        pub opt_field1: Option<i32>,
+        pub req_field1: String,
+        #[default = "1"]
+        pub def_field1: i32,
+        pub req_field2: u64,
+        pub opt_field2: Option<String>,
+        #[builder(skip)]
+        pub skip_field1: Vec<i32>,
+        #[default]
+        pub def_field2: bool,
+        pub req_field3: bool,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(staged)]
+    struct StructWithIntoDefaults {
+        pub req_field1: i32,
+        #[default = "\"hey\".into()"]
+        pub def_field1: String,
+        #[default = "Some(\"hey2\".into())"]
+        pub opt_field1: Option<String>,
+        #[default = "vec![\"hey3\".into()]"]
+        pub def_field2: Vec<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithSets {
+        pub req_field1: HashSet<String>,
+        #[default = "BTreeSet::new()"]
+        pub def_field1: BTreeSet<i32>,
+        pub opt_field1: Option<std::collections::HashSet<i32>>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct GenericStructWithWhere<T, U>
+    where
+        T: Iterator,
+        T::Item: Clone,
+        U: Clone,
+    {
+        pub req_field1: T::Item,
+        pub opt_field1: Option<U>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(name = "create")]
+    struct StructWithFactoryName {
+        pub req_field1: String,
+        pub req_field2: i32,
+        pub opt_field1: Option<i32>,
+    }
+
+    impl StructWithFactoryName {
+        fn new() -> Self {
+            Self::create("default".into(), 0)
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithDurations {
+        pub req_field1: std::time::Duration,
+        pub opt_field1: Option<Duration>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(no_inline)]
+    struct StructWithoutInline {
+        pub req_field1: String,
+        #[builder(getter)]
+        pub req_field2: Vec<i32>,
+        pub opt_field1: Option<bool>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(no_inline)]
+    struct TupleStructWithoutInline(String, Option<i32>);
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Matrix<T, const N: usize> {
+        data: [T; N],
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct GenericStructWithNestedArgs<'a, T, const N: usize, const M: usize> {
+        pub req_field1: Matrix<T, N>,
+        pub req_field2: Vec<&'a str>,
+        pub req_field3: Matrix<i32, { M }>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(mutable_with)]
+    struct StructWithMutableWith {
+        pub req_field1: String,
+        pub req_field2: Box<i32>,
+        pub opt_field1: Option<String>,
+        pub opt_field2: Option<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(staged, from_field)]
+    struct StructWithDefaultConst {
+        pub req_field1: String,
+        #[builder(default_const = "DEFAULT_TIMEOUT")]
+        pub def_field1: i32,
+        #[builder(default_const = "DEFAULT_NAME")]
+        pub opt_field1: Option<&'static str>,
+    }
+
+    impl StructWithDefaultConst {
+        const DEFAULT_TIMEOUT: i32 = 30;
+        const DEFAULT_NAME: Option<&'static str> = Some("default");
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(diff)]
+    struct StructWithDiff {
+        pub req_field1: String,
+        pub req_field2: i32,
+        pub opt_field1: Option<String>,
+        pub opt_field2: Option<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(option_naming = "explicit")]
+    struct StructWithExplicitOptionNaming {
+        pub req_field1: String,
+        pub opt_field1: Option<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(doc_examples)]
+    struct StructWithDocExamples {
+        /// Documented field
+        pub req_field1: String,
+        #[default = "10"]
+        pub def_field1: i32,
+        pub opt_field1: Option<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct GenericStructWithNestedRequired<T: Clone> {
+        pub req_field1: Vec<T>,
+        pub opt_field1: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct GenericStructWithNestedOptional<T: Clone> {
+        pub req_field1: String,
+        pub opt_field1: Option<Vec<T>>,
+    }
+
+    mod restricted_access {
+        use rsb_derive::Builder;
+
+        #[derive(Debug, Clone, PartialEq, Builder)]
+        pub struct StructWithRestrictedAccess {
+            pub req_field1: String,
+            pub(in crate::tests) req_field2: i32,
+            opt_field1: Option<i32>,
+        }
+
+        impl StructWithRestrictedAccess {
+            pub fn opt_field1_value(&self) -> Option<i32> {
+                self.clone().with_opt_field1(1).opt_field1
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithArrays {
+        pub req_field1: [i32; 3],
+        pub opt_field1: Option<[u8; 4]>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithCow<'a> {
+        pub req_field1: Cow<'a, str>,
+        pub opt_field1: Option<Cow<'a, [i32]>>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(pattern = "owned")]
+    struct StructWithOwnedPattern {
+        pub req_field1: String,
+        pub opt_field1: Option<i32>,
+        pub vec_field1: Vec<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(pattern = "mutable")]
+    struct StructWithMutablePattern {
+        pub req_field1: String,
+        pub opt_field1: Option<i32>,
+        pub vec_field1: Vec<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithFromSetters {
+        pub name: String,
+        pub count: i32,
+        pub label: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder, serde::Serialize, serde::Deserialize)]
+    #[builder(init_derive(Debug, PartialEq, serde::Serialize, serde::Deserialize))]
+    struct StructWithSerde {
+        #[serde(rename = "name")]
+        pub req_field1: String,
+        #[serde(default)]
+        pub req_field2: i32,
+        pub opt_field1: Option<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithEach {
+        #[builder(each = "tag")]
+        pub tags: Vec<String>,
+        #[builder(each = "label")]
+        pub labels: Option<BTreeSet<String>>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithPaths {
+        pub req_field1: PathBuf,
+        pub opt_field1: Option<std::path::PathBuf>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Default, Builder)]
+    struct StructWithOwnDerivedDefault {
+        pub opt_field1: Option<String>,
+        pub opt_field2: Option<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(typestate)]
+    struct StructWithTypestateValidation {
+        #[builder(validate = "validate_non_empty")]
+        pub req_field1: String,
+        #[builder(validate = "validate_positive")]
+        pub req_field2: i32,
+        pub opt_field1: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(typestate)]
+    struct StructWithTypestate<'a, T: Clone> {
+        pub req_field1: String,
+        pub req_field2: &'a str,
+        #[builder(required)]
+        pub req_opt_field1: Option<T>,
+        #[default = "10"]
+        pub def_field1: i32,
+        pub opt_field1: Option<i32>,
+        #[builder(skip)]
+        pub skip_field1: Vec<T>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithDefaultTry {
+        pub req_field1: String,
+        #[builder(default_try = "\"127.0.0.1\".parse()")]
+        pub def_field1: std::net::IpAddr,
+        #[builder(default_try = "\"8080\".parse().map(Some)")]
+        pub opt_field1: Option<u16>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithSkipSetters {
+        #[builder(skip_setters)]
+        pub id: u64,
+        pub req_field1: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[non_exhaustive]
+    struct StructWithNonExhaustive {
+        pub req_field1: String,
+        pub opt_field1: Option<i32>,
+        req_field2: i32,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithManyRequired {
+        pub req_field1: String,
+        pub req_field2: i32,
+        pub req_field3: i32,
+        pub req_field4: i32,
+        pub req_field5: i32,
+        pub req_field6: i32,
+        pub req_field7: i32,
+        #[builder(validate = "validate_positive")]
+        pub req_field8: i32,
+        pub opt_field1: Option<i32>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct TupleStructWithManyRequired(i32, i32, i32, i32, i32, i32, i32, i32);
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct GenericStructWithDefaultParam<T = String, const N: usize = 2> {
+        pub req_field1: T,
+        pub req_field2: [i32; N],
+        pub opt_field1: Option<T>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(staged)]
+    struct StagedGenericStructWithDefaultParam<T = String> {
+        pub req_field1: T,
+        pub opt_field1: Option<T>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(typestate)]
+    struct TypestateGenericStructWithDefaultParam<T = String> {
+        pub req_field1: T,
+        pub opt_field1: Option<T>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(field_defaults(into))]
+    struct StructWithFieldDefaultsInto {
+        pub req_field1: String,
+        pub opt_field1: Option<String>,
+        #[default = "String::new()"]
+        pub def_field1: String,
+        #[builder(into = false)]
+        pub opt_field2: Option<i64>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithFlagSetters {
+        pub req_field1: String,
+        #[default = "false"]
+        #[builder(setter(strip_bool))]
+        pub verbose: bool,
+        #[default = "false"]
+        #[builder(setter(strip_bool))]
+        pub dry_run: bool,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(from_field)]
+    struct GenericStructWithPhantomParam<T>
+    where
+        T: std::fmt::Display,
+    {
+        pub req_field1: i32,
+        pub opt_field1: Option<i32>,
+        #[builder(skip, skip_setters)]
+        marker: std::marker::PhantomData<T>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(staged)]
+    struct StagedGenericStructWithPhantomParam<T>
+    where
+        T: std::fmt::Display,
+    {
+        pub req_field1: i32,
+        #[default = "std::marker::PhantomData"]
+        #[builder(skip_setters)]
+        marker: std::marker::PhantomData<T>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(typestate)]
+    struct TypestateGenericStructWithPhantomParam<T>
+    where
+        T: std::fmt::Display,
+    {
+        pub req_field1: i32,
+        #[builder(skip, skip_setters)]
+        marker: std::marker::PhantomData<T>,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Email(String);
+
+    impl TryFrom<String> for Email {
+        type Error = String;
+
+        fn try_from(value: String) -> Result<Self, Self::Error> {
+            if value.contains('@') {
+                Ok(Email(value))
+            } else {
+                Err(format!("invalid email: {}", value))
+            }
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithTryFrom {
+        #[builder(try_from = "String")]
+        pub req_field1: Email,
+        #[builder(try_from = "String")]
+        pub opt_field1: Option<Email>,
+        #[builder(try_from = "u64")]
+        pub opt_field2: Option<u8>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(derive_default)]
+    struct StructWithDerivedDefault {
+        pub req_field1: String,
+        pub req_field2: Vec<i32>,
+        #[builder(required)]
+        pub req_field3: Option<i32>,
+        #[default = "10"]
+        pub def_field1: i32,
+        pub opt_field1: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithChars {
+        pub req_field1: char,
+        #[default = "'A'"]
+        pub def_field1: char,
+        #[default = "'\\''"]
+        pub def_field2: char,
+        pub opt_field1: Option<char>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(clone_setters)]
+    struct StructWithCloneSetters {
+        pub req_field1: String,
+        pub opt_field1: Option<i32>,
+        #[default = "false"]
+        #[builder(setter(strip_bool))]
+        pub verbose: bool,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(trace)]
+    struct StructWithTrace {
+        pub req_field1: String,
+        pub opt_field1: Option<i32>,
+        pub vec_field1: Vec<i32>,
+    }
+
+    struct TraceLogger {
+        lines: std::sync::Mutex<Vec<String>>,
+    }
+
+    impl log::Log for TraceLogger {
+        fn enabled(&self, metadata: &log::Metadata) -> bool {
+            metadata.level() <= log::Level::Debug
+        }
+
+        fn log(&self, record: &log::Record) {
+            self.lines.lock().unwrap().push(record.args().to_string());
+        }
+
+        fn flush(&self) {}
+    }
+
+    static TRACE_LOGGER: TraceLogger = TraceLogger {
+        lines: std::sync::Mutex::new(Vec::new()),
+    };
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithNested {
+        pub req_field1: SimpleStrValueStruct,
+        pub opt_field1: Option<SimpleStrValueStruct>,
+    }
+
+    fn default_timeout() -> u64 {
+        30
+    }
+
+    fn default_labels() -> Vec<String> {
+        vec!["default".into()]
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    struct StructWithDefaultFn {
+        pub req_field1: String,
+        #[builder(default_fn = "default_timeout")]
+        pub def_field1: u64,
+        #[builder(default_fn = "self::default_labels")]
+        pub def_field2: Vec<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    #[builder(staged)]
+    struct StructWithCfgFields {
+        pub req_field1: String,
+        #[cfg(test)]
+        pub req_field2: i32,
+        #[cfg(feature = "extra")]
+        pub req_field3: i32,
+        #[cfg(feature = "extra")]
+        pub opt_field1: Option<String>,
+    }
+
+    #[test]
+    fn new_str_value_struct() {
+        let s1: SimpleStrValueStruct = SimpleStrValueStruct::new("hey".into(), 0);
+
+        assert_eq!(s1.req_field1, String::from("hey"));
+    }
+
+    #[test]
+    fn fill_str_value_struct() {
+        let s1 = SimpleStrValueStruct {
+            req_field1: "hey".into(),
+            req_field2: 0,
+            opt_field1: None,
+            opt_field2: None,
+        }
+        .opt_field1("hey".into())
+        .clone();
+
+        assert_eq!(s1.opt_field1, Some("hey".into()));
+
+        let s1c = SimpleStrValueStruct {
+            req_field1: "hey".into(),
+            req_field2: 0,
+            opt_field1: None,
+            opt_field2: None,
+        }
+        .with_opt_field1("hey2".into());
+        assert_eq!(s1c.opt_field1, Some("hey2".into()));
+
+        assert_eq!(s1c.without_opt_field1().opt_field1, None);
+    }
+
+    #[test]
+    fn into_str_value_struct() {
+        let s1: SimpleStrValueStruct = SimpleStrValueStructInit {
+            req_field1: "hey".into(),
+            req_field2: 0,
+        }
+        .into();
+
+        let s11 = s1.clone().with_opt_field1("hey".into()).with_req_field2(10);
+
+        assert_eq!(s1.req_field1, String::from("hey"));
+        assert_eq!(s11.req_field1, String::from("hey"));
+    }
+
+    #[test]
+    fn all_together_test() {
+        let s1: SimpleStrValueStruct = SimpleStrValueStruct::from(SimpleStrValueStructInit {
+            req_field1: "hey".into(),
+            req_field2: 0,
+        })
+        .with_opt_field1("hey".into())
+        .with_opt_field2(10);
+
+        assert_eq!(s1.req_field1, String::from("hey"));
+        assert_eq!(s1.opt_field1, Some(String::from("hey")));
+    }
+
+    #[test]
+    fn all_together_mutable_test() {
+        let mut s1: SimpleStrValueStruct = SimpleStrValueStruct::from(SimpleStrValueStructInit {
+            req_field1: "hey".into(),
+            req_field2: 0,
+        });
+
+        s1.opt_field1("hey".into())
+            .opt_field2(10)
+            .reset_opt_field2();
+
+        assert_eq!(s1.req_field1, String::from("hey"));
+        assert_eq!(s1.opt_field1, Some(String::from("hey")));
+    }
+
+    #[test]
+    fn generic_struct_test() {
+        let g1: GenericValueStruct<String, i64> =
+            GenericValueStruct::from(GenericValueStructInit {
+                gen_field1: "hey".into(),
+                gen_field2: "hey2".into(),
+            })
+            .with_opt_gen_field1("hey".into());
+
+        assert_eq!(g1.gen_field1, String::from("hey"));
+        assert_eq!(g1.opt_gen_field1, Some(String::from("hey")));
+    }
+
+    #[test]
+    fn generic_struct_with_bounds_test() {
+        let g1: GenericValueStructWithBounds<i64> =
+            GenericValueStructWithBounds::from(GenericValueStructWithBoundsInit { gen_field1: 17 })
+                .with_opt_gen_field1(37);
+
+        assert_eq!(g1.gen_field1, 17);
+        assert_eq!(g1.opt_gen_field1, Some(37));
+    }
+
+    #[test]
+    fn generic_struct_with_bounds_where_test() {
+        let g1: GenericValueStructWithBoundsWhere<i64> =
+            GenericValueStructWithBoundsWhere::from(GenericValueStructWithBoundsWhereInit {
+                gen_field1: 17,
+            })
+            .with_opt_gen_field1(37);
+
+        assert_eq!(g1.gen_field1, 17);
+        assert_eq!(g1.opt_gen_field1, Some(37));
+    }
+
+    #[test]
+    fn struct_with_defaults_test() {
+        let sd1 = StructWithDefault::from(StructWithDefaultInit {
+            req_field1: "test".into(),
+        });
+
+        assert_eq!(sd1.req_field2, 10);
+        assert_eq!(sd1.opt_field2, Some(11));
+    }
+
+    #[test]
+    fn opt_setter_struct() {
+        let s1: SimpleStrValueStruct = SimpleStrValueStructInit {
+            req_field1: "hey".into(),
+            req_field2: 0,
+        }
+        .into();
+
+        let s11 = s1.clone().opt_opt_field1(Some("hey".into()));
+
+        assert_eq!(s11.opt_field1, Some(String::from("hey")));
+    }
+
+    #[test]
+    fn different_access_struct() {
+        let s1 = StructWithDifferentAccess::new("hey".into(), 0)
+            .opt_field1("hey".into())
+            .req_field2(0)
+            .clone();
+
+        assert_eq!(s1.opt_field1, Some("hey".into()));
+    }
+
+    #[test]
+    #[allow(clippy::useless_conversion)]
+    fn struct_with_lifetimes() {
+        let s1 = StructWithLifetime::new("hey".into())
+            .opt_field("hey".into())
+            .clone();
+
+        assert_eq!(s1.opt_field, Some("hey".into()));
+    }
+
+    #[test]
+    fn struct_with_floats() {
+        let s1 = StructWithFloats::new(1.5).with_opt_field1(2.5);
+
+        assert_eq!(s1.req_field1, 1.5);
+        assert_eq!(s1.opt_field1, Some(2.5));
+
+        let s2: StructWithFloats = StructWithFloatsInit { req_field1: 0.5 }.into();
+        let s21 = s2.with_req_field1(3.0);
+
+        assert_eq!(s21.req_field1, 3.0);
+        assert_eq!(s21.opt_field1, None);
+    }
+
+    #[test]
+    fn struct_with_flags_toggle() {
+        let mut s1 = StructWithFlags::new(false);
+
+        s1.toggle_req_flag1().toggle_req_flag2().opt_flag1(true);
+
+        assert!(s1.req_flag1);
+        assert!(!s1.req_flag2);
+        assert_eq!(s1.opt_flag1, Some(true));
+
+        s1.req_flag2(true).toggle_req_flag2().toggle_req_flag2();
+
+        assert!(s1.req_flag2);
+    }
+
+    #[test]
+    fn struct_with_into_setters() {
+        let mut s1 = StructWithInto::new("hey".into(), 0)
+            .with_req_field1("hey2")
+            .with_opt_field1("hey3");
+
+        assert_eq!(s1.req_field1, String::from("hey2"));
+        assert_eq!(s1.opt_field1, Some(String::from("hey3")));
+
+        s1.req_field1("hey4").opt_field1(String::from("hey5"));
+
+        assert_eq!(s1.req_field1, String::from("hey4"));
+        assert_eq!(s1.opt_field1, Some(String::from("hey5")));
+    }
+
+    #[test]
+    fn struct_with_all_optional_default() {
+        let s1: StructWithAllOptional<String> = StructWithAllOptional::default();
+
+        assert_eq!(s1, StructWithAllOptional::new());
+        assert_eq!(s1.def_field1, 10);
+        assert_eq!(s1.opt_field2, Some(11));
+        assert_eq!(s1.opt_gen_field1, None);
+    }
+
+    #[test]
+    fn struct_with_validation_try_new() {
+        let s1 = StructWithValidation::try_new("hey".into(), 1);
+
+        assert_eq!(s1, Ok(StructWithValidation::new("hey".into(), 1)));
+
+        assert_eq!(
+            StructWithValidation::try_new("".into(), 1),
+            Err("value must not be empty".into())
+        );
+
+        assert_eq!(
+            StructWithValidation::try_new("".into(), 0),
+            Err("value must not be empty".into())
+        );
+
+        assert_eq!(
+            StructWithValidation::try_new("hey".into(), 0),
+            Err("0 is not positive".into())
+        );
+
+        // new stays unchecked
+        assert_eq!(StructWithValidation::new("".into(), 0).req_field1, "");
+    }
+
+    #[test]
+    fn tuple_struct() {
+        let s1 = TupleStruct::new("hey".into());
+
+        assert_eq!(s1, TupleStruct("hey".into(), None));
+
+        let s11 = s1.clone().with_0("hey2".into()).with_1(10);
+
+        assert_eq!(s11, TupleStruct("hey2".into(), Some(10)));
+        assert_eq!(s11.clone().without_1(), TupleStruct("hey2".into(), None));
+        assert_eq!(s11.opt_1(Some(11)).1, Some(11));
+    }
+
+    #[test]
+    fn generic_tuple_struct() {
+        let s1: GenericTupleStruct<String> =
+            GenericTupleStruct::new("hey".into()).with_2("hey2".into());
+
+        assert_eq!(
+            s1,
+            GenericTupleStruct("hey".into(), 10, Some("hey2".into()))
+        );
+    }
+
+    #[test]
+    fn struct_with_getters() {
+        let s1 = getters::StructWithGetters::new("hey".into(), 0);
+
+        assert_eq!(s1.get_req_field1(), "hey");
+        assert_eq!(s1.get_opt_field1(), None);
+
+        let s11 = s1.with_opt_field1("hey2".into());
+
+        assert_eq!(s11.get_opt_field1(), Some(&String::from("hey2")));
+    }
+
+    #[test]
+    fn struct_with_non_ascii_default() {
+        let s1 = StructWithNonAsciiDefault::new("hey".into());
+
+        assert_eq!(s1.req_field2, 'é');
+        assert_eq!(s1.opt_field1, Some('ü'));
+    }
+
+    #[test]
+    fn struct_with_string_defaults() {
+        let s1 = StructWithStringDefaults::new(0);
+
+        assert_eq!(s1.def_field1, "hey");
+        assert_eq!(s1.def_field2, "hey");
+        assert_eq!(s1.opt_field1, Some(r#"{"k":1}"#.into()));
+    }
+
+    #[test]
+    fn struct_with_vec_items() {
+        let mut s1 = StructWithVec::new("hey".into(), vec![1])
+            .with_vec_field1_item("hey1".into())
+            .with_vec_field1_item("hey2".into())
+            .with_vec_field2_item(2);
+
+        s1.push_vec_field1("hey3".into()).push_vec_field2(3);
+
+        assert_eq!(s1.vec_field1, vec!["hey1", "hey2", "hey3"]);
+        assert_eq!(s1.vec_field2, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn struct_with_map_entries() {
+        let mut s1: StructWithMaps<i32, String> = StructWithMaps::new()
+            .with_map_field1_entry("hey1".into(), 1)
+            .with_map_field2_entry(1, "hey1".into());
+
+        s1.insert_map_field1("hey2".into(), 2)
+            .insert_map_field2(2, "hey2".into());
+
+        assert_eq!(
+            s1.map_field1,
+            HashMap::from([("hey1".into(), 1), ("hey2".into(), 2)])
+        );
+        assert_eq!(
+            s1.map_field2,
+            BTreeMap::from([(1, "hey1".into()), (2, "hey2".into())])
+        );
+    }
+
+    #[test]
+    fn struct_with_lifetimes_and_generics() {
+        let owned = String::from("hey");
+        let s1: StructWithLifetimeAndGenerics<i32> = StructWithLifetimeAndGenericsInit {
+            req_field1: owned.as_str(),
+            req_field2: 10,
+            req_field3: "hey3",
+        }
+        .into();
+
+        let s11 = s1.with_opt_field1(&owned[1..]).with_opt_field2(11);
+
+        assert_eq!(s11.req_field1, "hey");
+        assert_eq!(s11.req_field3, "hey3");
+        assert_eq!(s11.opt_field1, Some("ey"));
+        assert_eq!(s11.opt_field2, Some(11));
+    }
+
+    #[test]
+    fn struct_with_const_generics() {
+        let s1: StructWithConstGenerics<3, 2> = StructWithConstGenerics::new([1, 2, 3], vec![]);
+
+        assert_eq!(s1.req_field1, [1, 2, 3]);
+
+        let s2: StructWithConstGenerics<2, 4> = StructWithConstGenericsInit {
+            req_field1: [1, 2],
+            req_field2: vec![[3, 4]],
+        }
+        .into();
+
+        let s21 = s2.with_opt_field1([5, 6, 7, 8]);
+
+        assert_eq!(s21.req_field2, vec![[3, 4]]);
+        assert_eq!(s21.opt_field1, Some([5, 6, 7, 8]));
+    }
+
+    #[test]
+    fn struct_with_renamed_setters() {
+        let mut s1 = StructWithRename::new(1)
+            .with_value(2)
+            .with_label("hey".into());
+
+        assert_eq!(s1.v, 2);
+        assert_eq!(s1.opt_l, Some("hey".into()));
+
+        s1.value(3).reset_label();
+
+        assert_eq!(s1.v, 3);
+        assert_eq!(s1.clone().without_label().opt_l, None);
+        assert_eq!(s1.opt_label(Some("hey2".into())).opt_l, Some("hey2".into()));
+    }
+
+    #[test]
+    fn struct_with_skipped_fields() {
+        let s1 = StructWithSkip::new("hey".into());
+
+        assert_eq!(s1.skip_field1, Vec::<String>::new());
+        assert_eq!(s1.skip_field2, 0);
+
+        let s2: StructWithSkip = StructWithSkipInit {
+            req_field1: "hey".into(),
+        }
+        .into();
+
+        assert_eq!(s2.with_skip_field2(10).skip_field2, 10);
+    }
+
+    #[test]
+    fn struct_with_bare_default() {
+        let s1: StructWithBareDefault = StructWithBareDefaultInit { req_field1: 1 }.into();
+
+        assert_eq!(s1, StructWithBareDefault::new(1));
+        assert_eq!(s1.def_field1, String::new());
+        assert_eq!(s1.def_field2, Vec::<i32>::new());
+        assert_eq!(s1.opt_field1, None);
+        assert_eq!(s1.with_def_field1("hey".into()).def_field1, "hey");
+    }
+
+    #[test]
+    fn into_init_round_trip() {
+        let s1 = SimpleStrValueStruct::new("hey".into(), 10).with_opt_field1("hey2".into());
+        let s1_init: SimpleStrValueStructInit = s1.into_init();
+
+        assert_eq!(s1_init.req_field1, "hey");
+        assert_eq!(s1_init.req_field2, 10);
+
+        let s2: SimpleStrValueStruct = s1_init.into();
+
+        assert_eq!(s2, SimpleStrValueStruct::new("hey".into(), 10));
+
+        let g1: GenericValueStruct<String, i64> =
+            GenericValueStruct::new("hey".into(), "hey2".into()).with_opt_gen_field2(10);
+        let g2: GenericValueStruct<String, i64> = GenericValueStruct::from(g1.into_init());
+
+        assert_eq!(g2.gen_field1, "hey");
+        assert_eq!(g2.gen_field2, "hey2");
+        assert_eq!(g2.opt_gen_field2, None);
+    }
+
+    #[test]
+    fn staged_builder_success() {
+        let s1: Result<StructWithStagedBuilder<i64>, String> = StructWithStagedBuilder::builder()
+            .with_req_field1("hey".into())
+            .with_req_field2(1)
+            .with_opt_field1("hey2".into())
+            .build();
+
+        assert_eq!(
+            s1,
+            Ok(StructWithStagedBuilder::new("hey".into(), 1).with_opt_field1("hey2".into()))
+        );
+
+        let s2 = StructWithStagedBuilder::builder()
+            .with_req_field1("hey".into())
+            .with_req_field2(1)
+            .with_def_field1(11)
+            .build()
+            .unwrap();
+
+        assert_eq!(s2.def_field1, 11);
+        assert_eq!(s2.opt_field1, None);
+    }
+
+    #[test]
+    fn staged_builder_missing_fields() {
+        let s1: Result<StructWithStagedBuilder<i64>, String> = StructWithStagedBuilder::builder()
+            .with_opt_field1("hey".into())
+            .build();
+
+        assert_eq!(
+            s1,
+            Err("missing required fields: req_field1, req_field2".into())
+        );
+
+        let s2: Result<StructWithStagedBuilder<i64>, String> = StructWithStagedBuilder::builder()
+            .with_req_field2(1)
+            .build();
+
+        assert_eq!(s2, Err("missing required fields: req_field1".into()));
+    }
+
+    #[test]
+    fn staged_builder_validation() {
+        let s1 = StructWithStagedValidation::builder()
+            .with_type("hey".into())
+            .with_req_field2(1)
+            .build();
+        assert_eq!(s1, StructWithStagedValidation::try_new("hey".into(), 1));
+        assert!(s1.is_ok());
+
+        let s2 = StructWithStagedValidation::builder()
+            .with_type("".into())
+            .with_req_field2(1)
+            .build();
+        assert_eq!(s2, Err(validate_non_empty("").unwrap_err()));
+
+        let s3 = StructWithStagedValidation::builder()
+            .with_type("hey".into())
+            .with_req_field2(0)
+            .build();
+        assert_eq!(s3, Err(validate_positive(&0).unwrap_err()));
+
+        let s4 = StructWithStagedValidation::builder().build();
+        assert_eq!(s4, Err("missing required fields: type, req_field2".into()));
+    }
+
+    #[test]
+    fn struct_with_str_slices() {
+        let owned1 = String::from("hey1");
+        let owned2 = String::from("hey2");
+
+        let mut s1: StructWithStrSlices = StructWithStrSlicesInit {
+            req_field1: &owned1,
+            req_field2: &owned2,
+        }
+        .into();
+
+        s1.req_field1("hey3").opt_field1(&owned2[..2]);
+
+        assert_eq!(s1.req_field1, "hey3");
+        assert_eq!(s1.req_field2, "hey2");
+        assert_eq!(s1.def_field1, "default");
+        assert_eq!(s1.opt_field1, Some("he"));
+
+        let s11 = s1.with_req_field2(&owned1).with_def_field1("static");
+
+        assert_eq!(s11.req_field2, "hey1");
+        assert_eq!(s11.def_field1, "static");
+    }
+
+    #[test]
+    fn struct_with_box() {
+        let s1 = boxes::StructWithBox::new(Box::new("hey".into()))
+            .with_req_field1("hey2".into())
+            .with_def_field1(11);
+
+        assert_eq!(s1.req_field1, Box::new(String::from("hey2")));
+        assert_eq!(s1.def_field1, Box::new(11));
+
+        let mut s2 = s1.with_req_field1_boxed(Box::new("hey3".into()));
+
+        assert_eq!(*s2.req_field1, "hey3");
+
+        s2.req_field1("hey4".into()).def_field1_boxed(Box::new(12));
+
+        assert_eq!(*s2.req_field1, "hey4");
+        assert_eq!(*s2.def_field1, 12);
+    }
+
+    #[test]
+    fn struct_with_unsized_boxes() {
+        let s1 = StructWithUnsizedBoxes::new(Box::new(|| 1))
+            .with_req_field1(Box::new(|| 2))
+            .with_opt_field1(vec![1, 2].into_boxed_slice())
+            .with_opt_field2("hey".into());
+        assert_eq!((s1.req_field1)(), 2);
+        assert_eq!(s1.opt_field1.as_deref(), Some(&[1u8, 2][..]));
+        assert_eq!(s1.opt_field2.as_deref(), Some("hey"));
+    }
+
+    #[test]
+    fn struct_with_shared_pointers() {
+        let shared = Arc::new(String::from("shared"));
+
+        let mut s1 = StructWithSharedPointers::new(Arc::new("hey".into()), Rc::new(vec![]))
+            .with_req_field1("hey2".into())
+            .with_req_field2(vec![1]);
+
+        assert_eq!(s1.req_field1, Arc::new(String::from("hey2")));
+        assert_eq!(*s1.req_field2, vec![1]);
+
+        s1.req_field1_arc(shared.clone())
+            .req_field2_rc(Rc::new(vec![2]))
+            .opt_field1_arc(shared.clone());
+
+        assert!(Arc::ptr_eq(&s1.req_field1, &shared));
+        assert_eq!(*s1.req_field2, vec![2]);
+        assert_eq!(s1.opt_field1, Some(shared.clone()));
+
+        let s11 = s1.with_req_field1_arc(Arc::new("hey3".into()));
+
+        assert_eq!(*s11.req_field1, "hey3");
+    }
+
+    #[test]
+    fn struct_with_unsized_shared_pointers() {
+        let s1 = StructWithUnsizedSharedPointers::new("hey".into())
+            .with_req_field1("hey2".into())
+            .with_opt_field1("hey3".into())
+            .with_opt_field2(Arc::new(1));
+        assert_eq!(&*s1.req_field1, "hey2");
+        assert_eq!(s1.opt_field1.as_deref(), Some("hey3"));
+        assert_eq!(format!("{:?}", s1.opt_field2), "Some(1)");
+    }
+
+    #[test]
+    fn struct_map_fields() {
+        let s1 = StructWithNested::new(SimpleStrValueStruct::new("hey".into(), 1))
+            .map_req_field1(|v| v.with_opt_field1("hey2".into()))
+            .map_opt_field1(|v| v.with_req_field2(2));
+
+        assert_eq!(s1.req_field1.opt_field1, Some("hey2".into()));
+        assert_eq!(s1.opt_field1, None);
+
+        let s2 = s1
+            .with_opt_field1(SimpleStrValueStruct::new("hey3".into(), 3))
+            .map_opt_field1(|v| v.with_req_field2(4));
+
+        assert_eq!(
+            s2.opt_field1,
+            Some(SimpleStrValueStruct::new("hey3".into(), 4))
+        );
+    }
+
+    #[test]
+    fn struct_with_default_fn() {
+        let s1: StructWithDefaultFn = StructWithDefaultFnInit {
+            req_field1: "hey".into(),
+        }
+        .into();
+
+        assert_eq!(s1.def_field1, 30);
+        assert_eq!(s1.def_field2, vec![String::from("default")]);
+        assert_eq!(s1.with_def_field1(10).def_field1, 10);
+    }
+
+    #[test]
+    #[cfg(not(feature = "extra"))]
+    fn struct_with_cfg_fields() {
+        let s1 = StructWithCfgFields::new("hey".into(), 1).with_req_field2(2);
+        assert_eq!(s1.req_field2, 2);
+
+        let s2: StructWithCfgFields = StructWithCfgFieldsInit {
+            req_field1: "hey".into(),
+            req_field2: 2,
+        }
+        .into();
+        assert_eq!(s1, s2);
+
+        let s3 = StructWithCfgFields::builder()
+            .with_req_field1("hey".into())
+            .with_req_field2(2)
+            .build();
+        assert_eq!(s3, Ok(s2.clone()));
+
+        let s4: StructWithCfgFields = ("hey".to_string(), 2).into();
+        assert_eq!(s4, s2);
+    }
+
+    #[test]
+    #[cfg(feature = "extra")]
+    fn struct_with_cfg_fields_enabled() {
+        let s1 = StructWithCfgFields::new("hey".into(), 1, 2).with_opt_field1("hey2".into());
+        assert_eq!(s1.req_field3, 2);
+        assert_eq!(s1.opt_field1, Some("hey2".into()));
+    }
+
+    #[test]
+    fn struct_with_own_methods() {
+        let merged =
+            StructWithOwnMethods::new("hey".into()).merge(StructWithOwnMethods::new("2".into()));
+        assert_eq!(merged.req_field1, "hey2");
+
+        let applied = merged.apply(|s| s.with_opt_field1("hey3".into()));
+        assert_eq!(applied.opt_field1, Some("hey3".into()));
+
+        let converted: StructWithOwnMethods = "hey".to_string().into();
+        assert_eq!(converted.req_field1, "HEY");
+
+        assert_eq!(converted.with_defaults().req_field1, "default");
+    }
+
+    #[test]
+    fn struct_merge() {
+        let base = StructWithMerge::new("hey".into(), 1)
+            .with_opt_field1("hey2".into())
+            .with_opt_field2(2);
+
+        let merged = base.merge(StructWithMerge::new("override".into(), 10).with_opt_field2(20));
+
+        assert_eq!(merged.req_field1, "override");
+        assert_eq!(merged.req_field2, 10);
+        assert_eq!(merged.opt_field1, Some("hey2".into()));
+        assert_eq!(merged.opt_field2, Some(20));
+    }
+
+    #[test]
+    fn struct_with_optional_wrappers() {
+        let mut s1 = StructWithOptionalWrappers::new()
+            .with_opt_field1_item("hey".into())
+            .with_opt_field2(10)
+            .with_opt_field3_entry("hey".into(), 1);
+
+        assert_eq!(s1.opt_field1, Some(vec![String::from("hey")]));
+        assert_eq!(s1.opt_field2, Some(Box::new(10)));
+        assert_eq!(s1.opt_field3.as_ref().map(|m| m.len()), Some(1));
+
+        s1.push_opt_field1("hey2".into())
+            .opt_field2_boxed(Box::new(11))
+            .insert_opt_field3("hey2".into(), 2);
+
+        assert_eq!(
+            s1.opt_field1,
+            Some(vec![String::from("hey"), String::from("hey2")])
+        );
+        assert_eq!(s1.opt_field2, Some(Box::new(11)));
+        assert_eq!(s1.opt_field3.as_ref().map(|m| m.len()), Some(2));
+
+        let s2 = s1.without_opt_field1().with_opt_field1(vec!["hey3".into()]);
+        assert_eq!(s2.opt_field1, Some(vec![String::from("hey3")]));
+    }
+
+    #[test]
+    fn struct_with_custom_init_name() {
+        let s1: StructWithCustomInitName = CustomInitArgs {
+            req_field1: "hey".into(),
+        }
+        .into();
+
+        assert_eq!(s1, StructWithCustomInitName::new("hey".into()));
+        assert_eq!(s1.into_init().req_field1, "hey");
+    }
+
+    #[test]
+    fn struct_with_crate_visible_setters() {
+        use crate_visible::*;
+
+        let s1: StructWithCrateSetters = StructWithCrateSettersInit {
+            req_field1: "hey".into(),
+        }
+        .into();
+
+        let s2 = StructWithCrateSetters::new("hey".into()).with_opt_field1(10);
+
+        assert_eq!(s1.with_opt_field1(10), s2);
+    }
+
+    #[test]
+    fn struct_with_const_new() {
+        assert_eq!(CONST_STRUCT.req_field1, 10);
+        assert_eq!(CONST_STRUCT.req_field2, "hey");
+        assert_eq!(CONST_STRUCT.opt_field1, None);
+        assert_eq!(
+            CONST_STRUCT.with_opt_field1(11),
+            StructWithConstNew::new(10, "hey").with_opt_field1(11)
+        );
+    }
+
+    #[test]
+    fn struct_reset_to_default() {
+        let mut s1 = StructWithDefault::new("hey".into());
+        s1.req_field2(20);
+
+        assert_eq!(s1.req_field2, 20);
+
+        s1.reset_req_field2();
+
+        assert_eq!(s1.req_field2, 10);
+    }
+
+    #[test]
+    fn struct_with_non_zero() {
+        let s1 = StructWithNonZero::new(
+            NonZeroU32::new(1).unwrap(),
+            std::num::NonZeroI64::new(-1).unwrap(),
+        )
+        .with_opt_field1(NonZeroU32::new(2).unwrap());
+
+        let s2 = s1.clone().try_with_req_field1(10).unwrap();
+        assert_eq!(s2.req_field1.get(), 10);
+
+        let s3 = s2.try_with_req_field2(-10).unwrap();
+        assert_eq!(s3.req_field2.get(), -10);
+
+        assert_eq!(
+            s1.try_with_req_field1(0),
+            Err("req_field1 must be non-zero".to_string())
+        );
+    }
+
+    #[test]
+    fn ui_compile_errors() {
+        let t = trybuild::TestCases::new();
+        t.compile_fail("tests/ui/*.rs");
+    }
+
+    #[test]
+    fn struct_with_required_option() {
+        let s1 = StructWithRequiredOption::new(1, "hey".into());
+        assert_eq!(s1.opt_field1, Some("hey".into()));
+        assert_eq!(s1.opt_field2, None);
+
+        let s2: StructWithRequiredOption = StructWithRequiredOptionInit {
+            req_field1: 1,
+            opt_field1: "hey".into(),
+        }
+        .into();
+        assert_eq!(s1, s2);
+        assert_eq!(s2.clone().without_opt_field1().opt_field1, None);
+
+        assert_eq!(
+            StructWithRequiredOption::builder()
+                .with_req_field1(1)
+                .build(),
+            Err("missing required fields: opt_field1".into())
+        );
+        assert_eq!(
+            StructWithRequiredOption::builder()
+                .with_req_field1(1)
+                .with_opt_field1("hey".into())
+                .build(),
+            Ok(s2)
+        );
+    }
+
+    #[test]
+    fn struct_is_complete() {
+        let mut s1 = StructWithCompleteness::new("hey".into()).with_opt_field1("hey2".into());
+        assert!(!s1.is_complete());
+
+        s1.opt_field2(1);
+        assert!(s1.is_complete());
+
+        s1.reset_opt_field1();
+        assert!(!s1.is_complete());
+    }
+
+    #[test]
+    fn struct_with_documented_fields() {
+        let s1 = StructWithDocs::new("hey".into())
+            .with_req_field1("hey2".into())
+            .with_opt_field1(1);
+
+        assert_eq!(
+            StructWithDocs::builder()
+                .with_req_field1("hey2".into())
+                .with_opt_field1(1)
+                .build(),
+            Ok(s1)
+        );
+    }
+
+    #[test]
+    fn struct_conditional_setters() {
+        let s1 = SimpleStrValueStruct::new("hey".into(), 1)
+            .with_req_field1_if(false, "ignored".into())
+            .with_req_field2_if(true, 2)
+            .with_opt_field1_if(true, "hey2".into())
+            .with_opt_field2_if(false, 3);
+
+        assert_eq!(s1.req_field1, "hey");
+        assert_eq!(s1.req_field2, 2);
+        assert_eq!(s1.opt_field1, Some("hey2".into()));
+        assert_eq!(s1.opt_field2, None);
+    }
+
+    #[test]
+    fn generic_struct_with_default() {
+        let s1: GenericStructWithDefault<i32> = GenericStructWithDefault::new("hey".into());
+        assert_eq!(s1.def_field1, 0);
+        assert!(s1.def_field2.is_empty());
+
+        let s2: GenericStructWithDefault<String> = GenericStructWithDefaultInit {
+            req_field1: "hey".into(),
+        }
+        .into();
+        assert_eq!(s2.def_field1, String::new());
+        assert_eq!(s2.with_def_field1("hey2".into()).def_field1, "hey2");
+
+        let s3: GenericStructWithOnlyDefaults<u64> = Default::default();
+        assert_eq!(s3, GenericStructWithOnlyDefaults::new());
+        assert_eq!(s3.def_field1, 0);
+    }
+
+    #[test]
+    fn struct_take_optional_field() {
+        let mut s1 = SimpleStrValueStruct::new("hey".into(), 1).with_opt_field1("hey2".into());
+
+        assert_eq!(s1.take_opt_field1(), Some("hey2".into()));
+        assert_eq!(s1.opt_field1, None);
+        assert_eq!(s1.take_opt_field1(), None);
+    }
+
+    #[test]
+    fn struct_get_or_insert_optional_field() {
+        let mut s1 = StructWithNested::new(SimpleStrValueStruct::new("hey".into(), 1));
+
+        s1.get_or_insert_opt_field1(SimpleStrValueStruct::new("hey2".into(), 2))
+            .req_field2(3);
+
+        let inserted = s1.get_or_insert_opt_field1(SimpleStrValueStruct::new("hey4".into(), 4));
+        assert_eq!(inserted.req_field1, "hey2");
+        assert_eq!(inserted.req_field2, 3);
+    }
+
+    #[test]
+    fn struct_apply() {
+        let s1 = StructWithApply::new("hey".into(), 1)
+            .apply(|s| {
+                s.req_field2(2).opt_field1("hey2".into());
+            })
+            .with_opt_field2(3);
+
+        assert_eq!(s1.req_field2, 2);
+        assert_eq!(s1.opt_field1, Some("hey2".into()));
+        assert_eq!(s1.opt_field2, Some(3));
+    }
+
+    #[test]
+    fn struct_with_core_paths() {
+        let s1: StructWithCorePaths = StructWithCorePathsInit {
+            req_field1: "hey".into(),
+        }
+        .into();
+
+        let s2 = s1.with_opt_field1(1).with_opt_field2_item(2);
+
+        assert_eq!(s2.opt_field1, Some(1));
+        assert_eq!(s2.opt_field2, Some(vec![2]));
+    }
+
+    #[test]
+    fn struct_with_setter_prefix() {
+        let mut s1 = StructWithSetterPrefix::new("hey".into());
+        s1.set_req_field1("hey2".into()).set_opt_field1(1);
+
+        assert_eq!(s1.req_field1(), "hey2");
+        assert_eq!(s1.opt_field1, Some(1));
+        assert_eq!(s1.with_req_field1("hey3".into()).req_field1(), "hey3");
+    }
+
+    #[test]
+    fn struct_field_names() {
+        assert_eq!(
+            SimpleStrValueStruct::field_names(),
+            &["req_field1", "req_field2", "opt_field1", "opt_field2"]
+        );
+        assert_eq!(StructWithRename::field_names(), &["v", "opt_l"]);
+    }
+
+    #[test]
+    fn struct_with_enums() {
+        let s1 = StructWithEnums::new(Status::Running(1));
+        assert_eq!(s1.req_field1, Status::Running(1));
+        assert_eq!(s1.def_field1, Status::Idle);
+        assert_eq!(s1.opt_field1, None);
+
+        let s2 = s1
+            .with_def_field1(Status::Running(2))
+            .with_opt_field1(Status::Idle);
+        assert_eq!(s2.def_field1, Status::Running(2));
+        assert_eq!(s2.opt_field1, Some(Status::Idle));
+    }
+
+    #[test]
+    fn struct_new_params_order() {
+        let s1 = StructWithInterleavedFields::new("hey".into(), 2, true);
+
+        assert_eq!(s1.req_field1, "hey");
+        assert_eq!(s1.req_field2, 2);
+        assert!(s1.req_field3);
+        assert_eq!(s1.def_field1, 1);
+        assert!(!s1.def_field2);
+
+        let s2: StructWithInterleavedFields = StructWithInterleavedFieldsInit {
+            req_field1: "hey".into(),
+            req_field2: 2,
+            req_field3: true,
+        }
+        .into();
+
+        assert_eq!(s1, s2);
+    }
+
+    #[test]
+    fn struct_with_into_defaults() {
+        let mut s1 = StructWithIntoDefaults::new(1);
+        assert_eq!(s1.def_field1, "hey");
+        assert_eq!(s1.opt_field1, Some("hey2".into()));
+        assert_eq!(s1.def_field2, vec![String::from("hey3")]);
+
+        s1.def_field1("hey4".into()).reset_def_field1();
+        assert_eq!(s1.def_field1, "hey");
+
+        assert_eq!(
+            StructWithIntoDefaults::builder().with_req_field1(1).build(),
+            Ok(s1)
+        );
+    }
+
+    #[test]
+    fn struct_with_sets() {
+        let mut s1 = StructWithSets::new(HashSet::new())
+            .with_req_field1_item("hey".into())
+            .with_req_field1_item("hey2".into())
+            .with_def_field1_item(2)
+            .with_opt_field1_item(3);
+
+        s1.insert_req_field1("hey".into()).insert_def_field1(1);
+
+        assert_eq!(s1.req_field1.len(), 2);
+        assert!(s1.req_field1.contains("hey2"));
+        assert_eq!(
+            s1.def_field1.iter().copied().collect::<Vec<i32>>(),
+            vec![1, 2]
+        );
+        assert_eq!(s1.opt_field1, Some(HashSet::from([3])));
+    }
+
+    #[test]
+    fn struct_extend_collections() {
+        let mut s1 = StructWithVec::new("hey".into(), vec![1])
+            .with_vec_field2_extended(2..4)
+            .with_vec_field1_extended(vec!["hey".to_string()]);
+
+        s1.extend_vec_field2(vec![4, 5]);
+
+        assert_eq!(s1.vec_field2, vec![1, 2, 3, 4, 5]);
+        assert_eq!(s1.vec_field1, vec!["hey".to_string()]);
+
+        let s11 = StructWithOptionalWrappers::new().with_opt_field1_extended(vec!["hey".into()]);
+        assert_eq!(s11.opt_field1, Some(vec!["hey".to_string()]));
+
+        let s2 = StructWithSets::new(HashSet::new()).with_def_field1_extended([3, 1, 3]);
+        assert_eq!(s2.def_field1.into_iter().collect::<Vec<i32>>(), vec![1, 3]);
+    }
+
+    #[test]
+    fn struct_from_required_fields_tuple() {
+        let s1: SimpleStrValueStruct = ("hey".to_string(), 1).into();
+        assert_eq!(s1, SimpleStrValueStruct::new("hey".into(), 1));
+
+        let s2 = StructWithInterleavedFields::from(("hey".to_string(), 2, true));
+        assert_eq!(s2, StructWithInterleavedFields::new("hey".into(), 2, true));
+    }
+
+    #[test]
+    fn generic_struct_with_where_clause() {
+        let s1: GenericStructWithWhere<std::vec::IntoIter<i32>, String> =
+            GenericStructWithWhereInit::<std::vec::IntoIter<i32>> { req_field1: 1 }.into();
+
+        assert_eq!(s1.req_field1, 1);
+        assert_eq!(
+            s1.with_opt_field1("hey".into()).opt_field1,
+            Some("hey".into())
+        );
+    }
+
+    #[test]
+    fn struct_with_factory_name() {
+        let s1 = StructWithFactoryName::create("hey".into(), 1);
+        assert_eq!(s1.req_field1, "hey");
+        assert_eq!(StructWithFactoryName::new().req_field1, "default");
+
+        let s2: StructWithFactoryName = StructWithFactoryNameInit {
+            req_field1: "hey".into(),
+            req_field2: 1,
+        }
+        .into();
+        assert_eq!(s1, s2);
+
+        let s3: StructWithFactoryName = ("hey".to_string(), 1).into();
+        assert_eq!(s2, s3);
+    }
+
+    #[test]
+    fn struct_with_durations() {
+        let s1 = StructWithDurations::new(Duration::ZERO)
+            .with_req_field1_millis(1500)
+            .with_opt_field1_secs(2);
+
+        assert_eq!(s1.req_field1, Duration::from_millis(1500));
+        assert_eq!(s1.opt_field1, Some(Duration::from_secs(2)));
+
+        let s2 = s1.with_req_field1_secs(3).with_opt_field1_millis(10);
+
+        assert_eq!(s2.req_field1, Duration::from_secs(3));
+        assert_eq!(s2.opt_field1, Some(Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn struct_without_inline() {
+        let mut s1 = StructWithoutInline::new("hey".into(), vec![])
+            .with_req_field2_item(1)
+            .with_opt_field1(true);
+
+        s1.req_field1("hey2".into()).push_req_field2(2);
+
+        assert_eq!(s1.req_field1, "hey2");
+        assert_eq!(s1.get_req_field2(), &vec![1, 2]);
+        assert_eq!(s1.opt_field1, Some(true));
+
+        let t1 = TupleStructWithoutInline::new("hey".into()).with_1(1);
+        assert_eq!(t1, TupleStructWithoutInline("hey".into(), Some(1)));
+    }
+
+    #[test]
+    fn struct_try_from_init_with_validation() {
+        let s1: Result<StructWithValidation, String> = StructWithValidationInit {
+            req_field1: "hey".into(),
+            req_field2: 1,
+        }
+        .try_into();
+        assert_eq!(s1, Ok(StructWithValidation::new("hey".into(), 1)));
+
+        assert_eq!(
+            StructWithValidation::try_from(StructWithValidationInit {
+                req_field1: "".into(),
+                req_field2: 1,
+            }),
+            StructWithValidation::try_new("".into(), 1)
+        );
+        assert!(StructWithValidation::try_new("".into(), 1).is_err());
+    }
+
+    #[test]
+    fn generic_struct_with_nested_args() {
+        let s1: GenericStructWithNestedArgs<String, 2, 1> = GenericStructWithNestedArgsInit {
+            req_field1: Matrix {
+                data: ["hey".to_string(), "hey2".to_string()],
+            },
+            req_field2: vec!["hey3"],
+            req_field3: Matrix { data: [1] },
+        }
+        .into();
+
+        assert_eq!(s1.req_field1.data.len(), 2);
+        assert_eq!(s1.req_field2, vec!["hey3"]);
+        assert_eq!(s1.req_field3.data, [1]);
+    }
+
+    #[test]
+    fn struct_with_mutable_with() {
+        let mut s1 = StructWithMutableWith::new("hey".into(), Box::new(1));
+
+        s1.with_req_field1("hey2".into())
+            .with_req_field2(2)
+            .with_opt_field1("hey3".into())
+            .with_opt_field2(3)
+            .reset_opt_field2();
+
+        assert_eq!(s1.req_field1, "hey2");
+        assert_eq!(*s1.req_field2, 2);
+        assert_eq!(s1.opt_field1, Some("hey3".into()));
+        assert_eq!(s1.opt_field2, None);
+    }
+
+    #[test]
+    fn struct_with_default_const() {
+        let mut s1 = StructWithDefaultConst::new("hey".into());
+        assert_eq!(s1.def_field1, 30);
+        assert_eq!(s1.opt_field1, Some("default"));
+
+        s1.def_field1(10).reset_def_field1();
+        assert_eq!(s1.def_field1, 30);
+
+        assert_eq!(
+            StructWithDefaultConst::builder()
+                .with_req_field1("hey".into())
+                .build(),
+            Ok(s1)
+        );
+    }
+
+    #[test]
+    fn struct_optional_value_setters() {
+        let s1 = SimpleStrValueStruct::new("hey".into(), 1)
+            .with_req_field1_opt(Some("hey2".into()))
+            .with_req_field2_opt(None);
+
+        assert_eq!(s1.req_field1, "hey2");
+        assert_eq!(s1.req_field2, 1);
+    }
+
+    #[test]
+    fn struct_with_diff() {
+        let s1 = StructWithDiff::new("hey".into(), 1).with_opt_field2(1);
+        let s2 = s1.clone().with_req_field2(2).with_opt_field1("hey2".into());
+
+        assert!(s1.changed_fields(&s1.clone()).is_empty());
+        assert_eq!(s1.changed_fields(&s2), vec!["req_field2", "opt_field1"]);
+    }
+
+    #[test]
+    fn struct_from_single_required_field() {
+        let s1: StructWithDefaultConst = "hey".to_string().into();
+        assert_eq!(s1, StructWithDefaultConst::new("hey".into()));
+
+        let s2: GenericStructWithDefault<i32> = String::from("hey").into();
+        assert_eq!(s2, GenericStructWithDefault::new("hey".into()));
+    }
+
+    #[test]
+    fn struct_with_explicit_option_naming() {
+        let mut s1 = StructWithExplicitOptionNaming::new("hey".into()).maybe_opt_field1(Some(1));
+        assert_eq!(s1.opt_field1, Some(1));
+
+        s1.clear_opt_field1();
+        assert_eq!(s1.opt_field1, None);
+
+        s1.set_maybe_opt_field1(Some(2));
+        assert_eq!(s1.opt_field1, Some(2));
+
+        assert_eq!(s1.without_opt_field1().opt_field1, None);
+    }
+
+    #[test]
+    fn struct_with_doc_examples() {
+        let mut s1 = StructWithDocExamples::new("hey".into())
+            .with_def_field1(1)
+            .with_opt_field1(1);
+        s1.reset_def_field1().reset_opt_field1();
+
+        assert_eq!(s1, StructWithDocExamples::new("hey".into()));
+    }
+
+    #[test]
+    fn generic_struct_with_nested_required_init() {
+        fn from_init<T: Clone>(
+            init: GenericStructWithNestedRequiredInit<T>,
+        ) -> GenericStructWithNestedRequired<T> {
+            init.into()
+        }
+
+        let s1 = from_init(GenericStructWithNestedRequiredInit {
+            req_field1: vec![1, 2],
+        });
+        assert_eq!(s1, GenericStructWithNestedRequired::new(vec![1, 2]));
+        assert_eq!(s1.into_init().req_field1, vec![1, 2]);
+    }
+
+    #[test]
+    fn generic_struct_with_nested_optional_init() {
+        let s1: GenericStructWithNestedOptional<i32> = GenericStructWithNestedOptionalInit {
+            req_field1: "hey".into(),
+        }
+        .into();
+        assert_eq!(s1.opt_field1, None);
+
+        let init: GenericStructWithNestedOptionalInit = s1.with_opt_field1(vec![1]).into_init();
+        assert_eq!(init.req_field1, "hey");
+    }
+
+    #[test]
+    fn struct_with_defaults_reset() {
+        let s1 = StructWithResetDefaults::new("hey".into())
+            .with_req_field2(1)
+            .with_opt_field1("hey2".into())
+            .with_opt_field2(2)
+            .with_req_field1("hey3".into());
+
+        assert_eq!(
+            s1.with_defaults(),
+            StructWithResetDefaults::new("hey3".into())
+        );
+    }
+
+    #[test]
+    fn struct_with_restricted_access() {
+        let s1 =
+            restricted_access::StructWithRestrictedAccess::new("hey".into(), 1).with_req_field2(2);
+
+        assert_eq!(s1.req_field2, 2);
+        assert_eq!(s1.opt_field1_value(), Some(1));
+    }
+
+    #[test]
+    fn struct_with_arrays() {
+        let mut s1 = StructWithArrays::new([0; 3]);
+        s1.set_req_field1_at(0, 1)
+            .set_req_field1_at(1, 2)
+            .set_req_field1_at(2, 3);
+
+        assert_eq!(s1.req_field1, [1, 2, 3]);
+        assert_eq!(s1.with_opt_field1([1; 4]).opt_field1, Some([1; 4]));
+    }
+
+    #[test]
+    fn struct_with_validation_all() {
+        assert_eq!(
+            StructWithValidation::new("hey".into(), 1).validate_all(),
+            Ok(())
+        );
+
+        let errors = StructWithValidation::new("".into(), 0)
+            .validate_all()
+            .unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(
+            errors,
+            vec![
+                validate_non_empty("").unwrap_err(),
+                validate_positive(&0).unwrap_err()
+            ]
+        );
+    }
+
+    #[test]
+    fn struct_with_cow() {
+        let s1: StructWithCow<'static> =
+            StructWithCow::new(Cow::Borrowed("")).with_req_field1_borrowed("hey");
+        assert!(matches!(s1.req_field1, Cow::Borrowed("hey")));
+
+        let s2 = s1
+            .with_req_field1_owned("hey2".to_string())
+            .with_opt_field1_owned(vec![1, 2]);
+        assert!(matches!(s2.req_field1, Cow::Owned(ref value) if value == "hey2"));
+        assert_eq!(s2.opt_field1.as_deref(), Some([1, 2].as_slice()));
+    }
+
+    #[test]
+    fn struct_with_owned_pattern() {
+        let s1 = StructWithOwnedPattern::new("hey".into(), vec![])
+            .with_req_field1("hey2".into())
+            .with_opt_field1(1)
+            .with_vec_field1_item(1);
+
+        assert_eq!(s1.req_field1, "hey2");
+        assert_eq!(s1.vec_field1, vec![1]);
+        assert_eq!(s1.without_opt_field1().opt_field1, None);
+    }
+
+    #[test]
+    fn struct_with_mutable_pattern() {
+        let mut s1 = StructWithMutablePattern::new("hey".into(), vec![]);
+        s1.req_field1("hey2".into())
+            .opt_field1(1)
+            .push_vec_field1(1);
+
+        assert_eq!(s1.req_field1, "hey2");
+        assert_eq!(s1.vec_field1, vec![1]);
+        assert_eq!(s1.reset_opt_field1().opt_field1, None);
+    }
+
+    #[test]
+    fn struct_into_init_from() {
+        let s1 = SimpleStrValueStruct::new("hey".into(), 1).with_opt_field1("hey2".into());
+        let init: SimpleStrValueStructInit = s1.into();
+        assert_eq!(init.req_field1, "hey");
+        assert_eq!(init.req_field2, 1);
+
+        let s2: GenericStructWithNestedRequired<i32> =
+            GenericStructWithNestedRequired::new(vec![1]).with_opt_field1("hey".into());
+        let init: GenericStructWithNestedRequiredInit<i32> = s2.into();
+        assert_eq!(init.req_field1, vec![1]);
+    }
+
+    #[test]
+    fn struct_with_from_setters() {
+        let s1 = StructWithFromSetters::new(String::new(), 0)
+            .with_name_from("x")
+            .with_count_from(3u8)
+            .with_label_from("label");
+
+        assert_eq!(s1.name, "x");
+        assert_eq!(s1.count, 3);
+        assert_eq!(s1.label, Some("label".into()));
+    }
+
+    #[test]
+    fn struct_with_serde_init() {
+        let init: StructWithSerdeInit = serde_json::from_str(r#"{ "name": "hey" }"#).unwrap();
+        assert_eq!(
+            init,
+            StructWithSerdeInit {
+                req_field1: "hey".into(),
+                req_field2: 0
+            }
+        );
+
+        let s1: StructWithSerde = init.into();
+        assert_eq!(s1, StructWithSerde::new("hey".into(), 0));
+        assert_eq!(
+            serde_json::to_string(&s1.into_init()).unwrap(),
+            r#"{"name":"hey","req_field2":0}"#
+        );
+    }
+
+    #[test]
+    fn struct_with_each() {
+        let s1 = StructWithEach::new(vec![])
+            .with_tag("a".into())
+            .with_tag("b".into())
+            .with_label("c".into());
+
+        assert_eq!(s1.tags, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(s1.labels, Some(BTreeSet::from(["c".to_string()])));
+    }
+
+    #[test]
+    fn struct_build_with() {
+        let s1 = SimpleStrValueStruct::build_with(
+            SimpleStrValueStructInit {
+                req_field1: "hey".into(),
+                req_field2: 1,
+            },
+            |s| s.with_opt_field1("hey2".into()).with_opt_field2(2),
+        );
+
+        assert_eq!(
+            s1,
+            SimpleStrValueStruct::new("hey".into(), 1)
+                .with_opt_field1("hey2".into())
+                .with_opt_field2(2)
+        );
+
+        let s2: GenericStructWithNestedRequired<i32> = GenericStructWithNestedRequired::build_with(
+            GenericStructWithNestedRequiredInit {
+                req_field1: vec![1],
+            },
+            |s| s.with_opt_field1("hey".into()),
+        );
+        assert_eq!(s2.opt_field1, Some("hey".into()));
+    }
+
+    #[test]
+    fn struct_with_paths() {
+        let s1 = StructWithPaths::new(PathBuf::new())
+            .with_req_field1_path("/tmp/config.toml")
+            .with_opt_field1_path(String::from("data"));
+
+        assert_eq!(s1.req_field1, PathBuf::from("/tmp/config.toml"));
+        assert_eq!(s1.opt_field1, Some(PathBuf::from("data")));
+    }
+
+    #[test]
+    fn struct_with_typestate_builder() {
+        let s1 = StructWithTypestate::typestate_builder()
+            .with_opt_field1(1)
+            .with_req_field2("hey2")
+            .with_req_opt_field1(2u8)
+            .with_req_field1("hey".into())
+            .build();
+
+        assert_eq!(
+            s1,
+            StructWithTypestate::new("hey".into(), "hey2", 2).with_opt_field1(1)
+        );
+        assert_eq!(s1.def_field1, 10);
+        assert!(s1.skip_field1.is_empty());
+
+        let s2 = StructWithTypestate::typestate_builder()
+            .with_req_field1("hey".into())
+            .with_req_field2("hey2")
+            .with_def_field1(1)
+            .with_req_opt_field1(2u8)
+            .build();
+        assert_eq!(s2.def_field1, 1);
+    }
+
+    #[test]
+    fn struct_with_own_derived_default() {
+        let s1 = StructWithOwnDerivedDefault::default().with_opt_field2(1);
+        assert_eq!(s1.opt_field1, None);
+        assert_eq!(s1.opt_field2, Some(1));
+    }
+
+    #[test]
+    fn struct_with_typestate_validation() {
+        let s1 = StructWithTypestateValidation::typestate_builder()
+            .with_req_field2(1)
+            .with_req_field1("hey".into())
+            .build();
+        assert_eq!(s1, StructWithTypestateValidation::try_new("hey".into(), 1));
+        assert!(s1.is_ok());
+
+        let s2 = StructWithTypestateValidation::typestate_builder()
+            .with_req_field1("hey".into())
+            .with_req_field2(0)
+            .build();
+        assert_eq!(s2, Err(validate_positive(&0).unwrap_err()));
+    }
+
+    #[test]
+    fn struct_with_default_try() {
+        let s1 = StructWithDefaultTry::new("hey".into());
+        assert_eq!(s1.def_field1, std::net::IpAddr::from([127, 0, 0, 1]));
+        assert_eq!(s1.opt_field1, Some(8080));
+    }
+
+    #[test]
+    fn struct_with_skip_setters() {
+        let s1 = StructWithSkipSetters::new(1, "hey".into()).with_req_field1("hey2".into());
+        assert_eq!(s1.id, 1);
+
+        let s2: StructWithSkipSetters = StructWithSkipSettersInit {
+            id: 2,
+            req_field1: "hey".into(),
+        }
+        .into();
+        assert_eq!(s2.id, 2);
+    }
+
+    #[test]
+    fn take_required_string_and_collection_fields() {
+        let mut s1 = SimpleStrValueStruct::new("hey".into(), 0);
+        assert_eq!(s1.take_req_field1(), "hey");
+        assert!(s1.req_field1.is_empty());
+
+        let mut s2 = StructWithVec::new("hey".into(), vec![1, 2]);
+        assert_eq!(s2.take_vec_field2(), vec![1, 2]);
+        assert!(s2.vec_field2.is_empty());
+    }
+
+    #[test]
+    fn non_exhaustive_struct() {
+        let s1 = StructWithNonExhaustive::new("hey".into(), 1).with_opt_field1(10);
+        assert_eq!(s1.opt_field1, Some(10));
+
+        let s2: StructWithNonExhaustive = StructWithNonExhaustiveInit {
+            req_field1: "hey".into(),
+            req_field2: 1,
+        }
+        .into();
+        assert_eq!(s2.with_req_field2(2).req_field2, 2);
+    }
+
+    #[test]
+    fn scalar_ref_setters() {
+        let values = [1, 2, 3];
+        let s1 = values
+            .iter()
+            .fold(SimpleStrValueStruct::new("hey".into(), 0), |s, value| {
+                s.with_req_field2_ref(value).with_opt_field2_ref(value)
+            });
+        assert_eq!(s1.req_field2, 3);
+        assert_eq!(s1.opt_field2, Some(3));
+    }
+
+    #[test]
+    fn struct_with_many_required_fields() {
+        let s1 = StructWithManyRequired::new("hey".into(), 2, 3, 4, 5, 6, 7, 8);
+        assert_eq!(s1.req_field8, 8);
+        assert!(StructWithManyRequired::try_new("hey".into(), 2, 3, 4, 5, 6, 7, -8).is_err());
+
+        let s2 = TupleStructWithManyRequired::new(1, 2, 3, 4, 5, 6, 7, 8);
+        assert_eq!(s2.7, 8);
+    }
+
+    #[test]
+    fn generic_struct_with_default_param() {
+        let s1: GenericStructWithDefaultParam =
+            GenericStructWithDefaultParam::new("hey".into(), [1, 2]).with_opt_field1("hey2".into());
+        assert_eq!(s1.opt_field1, Some("hey2".into()));
+
+        let s2: GenericStructWithDefaultParam = GenericStructWithDefaultParamInit {
+            req_field1: "hey".into(),
+            req_field2: [1, 2],
+        }
+        .into();
+        assert_eq!(s2.req_field1, "hey");
+
+        let s3: GenericStructWithDefaultParam<i32, 1> = GenericStructWithDefaultParam::new(1, [2]);
+        assert_eq!(s3.req_field1, 1);
+
+        let s4: Result<StagedGenericStructWithDefaultParam, String> =
+            StagedGenericStructWithDefaultParam::builder()
+                .with_req_field1("hey".into())
+                .build();
+        assert_eq!(s4.unwrap().req_field1, "hey");
+
+        let s5: TypestateGenericStructWithDefaultParam =
+            TypestateGenericStructWithDefaultParam::typestate_builder()
+                .with_req_field1("hey".into())
+                .build();
+        assert_eq!(s5.req_field1, "hey");
+    }
+
+    #[test]
+    fn struct_with_field_defaults_into() {
+        let s1 = StructWithFieldDefaultsInto::new("hey".into())
+            .with_req_field1("hey2")
+            .with_opt_field1("hey3")
+            .with_def_field1("hey4")
+            .with_opt_field2(1);
+        assert_eq!(s1.req_field1, "hey2");
+        assert_eq!(s1.opt_field1, Some("hey3".into()));
+        assert_eq!(s1.def_field1, "hey4");
+        assert_eq!(s1.opt_field2, Some(1));
+    }
+
+    #[test]
+    fn optional_reference_fields() {
+        let req_value = String::from("hey");
+        let opt_value = String::from("hey2");
+
+        let s1 = StructWithLifetime::new(&req_value).with_opt_field(&opt_value);
+        assert_eq!(s1.opt_field, Some("hey2"));
+        assert_eq!(s1.clone().without_opt_field().opt_field, None);
+        assert_eq!(s1.clone().opt_opt_field(None).opt_field, None);
+
+        let mut s2 = s1.clone();
+        s2.reset_opt_field();
+        assert_eq!(s2.opt_field, None);
+        s2.opt_field(&opt_value[..3]);
+        assert_eq!(s2.opt_field, Some("hey"));
+        assert_eq!(s2.take_opt_field(), Some("hey"));
+        assert_eq!(s2.opt_field, None);
+    }
+
+    #[test]
+    fn struct_required_fields() {
+        assert_eq!(
+            SimpleStrValueStruct::REQUIRED_FIELDS,
+            &["req_field1", "req_field2"]
+        );
+        assert_eq!(StructWithRename::REQUIRED_FIELDS, &["v"]);
+        assert!(StructWithAllOptional::<i32>::REQUIRED_FIELDS.is_empty());
+    }
+
+    #[test]
+    fn struct_with_flag_setters() {
+        let s1 = StructWithFlagSetters::new("hey".into())
+            .with_verbose()
+            .with_dry_run();
+        assert!(s1.verbose);
+        assert!(s1.dry_run);
+
+        let s2 = s1.with_dry_run_value(false).with_verbose_if(false, false);
+        assert!(s2.verbose);
+        assert!(!s2.dry_run);
+    }
+
+    #[test]
+    fn generic_struct_with_phantom_param() {
+        let s1: GenericStructWithPhantomParam<String> =
+            GenericStructWithPhantomParam::new(1).with_opt_field1(2);
+        assert_eq!(s1.opt_field1, Some(2));
+
+        let s2: GenericStructWithPhantomParam<String> =
+            GenericStructWithPhantomParamInit { req_field1: 1 }.into();
+        assert_eq!(s2.req_field1, 1);
+        assert_eq!(s2.into_init().req_field1, 1);
+
+        let s3: GenericStructWithPhantomParam<String> = 1.into();
+        assert_eq!(s3.req_field1, 1);
+
+        let s4: GenericStructWithPhantomParam<String> = GenericStructWithPhantomParam::build_with(
+            GenericStructWithPhantomParamInit { req_field1: 1 },
+            |s| s,
+        );
+        assert_eq!(s4.req_field1, 1);
+
+        let s5: Result<StagedGenericStructWithPhantomParam<String>, String> =
+            StagedGenericStructWithPhantomParam::builder()
+                .with_req_field1(1)
+                .build();
+        assert_eq!(s5.unwrap().req_field1, 1);
+
+        let s6: TypestateGenericStructWithPhantomParam<String> =
+            TypestateGenericStructWithPhantomParam::typestate_builder()
+                .with_req_field1(1)
+                .build();
+        assert_eq!(s6.req_field1, 1);
+    }
+
+    #[test]
+    fn struct_with_try_from_setters() {
+        let s1 = StructWithTryFrom::new(Email("a@example.com".into()))
+            .try_with_req_field1("b@example.com".into())
+            .and_then(|s| s.try_with_opt_field1("c@example.com".into()))
+            .unwrap();
+        assert_eq!(s1.req_field1, Email("b@example.com".into()));
+        assert_eq!(s1.opt_field1, Some(Email("c@example.com".into())));
+
+        assert_eq!(
+            s1.clone().try_with_req_field1("b".into()),
+            Err("invalid email: b".to_string())
+        );
+        assert_eq!(
+            s1.clone().try_with_opt_field2(10).unwrap().opt_field2,
+            Some(10)
+        );
+        assert!(s1.try_with_opt_field2(1000).is_err());
+    }
+
+    #[test]
+    fn struct_with_derived_default() {
+        let s1 = StructWithDerivedDefault::default();
+        assert_eq!(s1.req_field1, "");
+        assert!(s1.req_field2.is_empty());
+        assert_eq!(s1.req_field3, None);
+        assert_eq!(s1.def_field1, 10);
+        assert_eq!(s1.opt_field1, None);
+    }
+
+    #[test]
+    fn struct_with_chars() {
+        let s1 = StructWithChars::new('x');
+        assert_eq!(s1.def_field1, 'A');
+        assert_eq!(s1.def_field2, '\'');
+
+        let chars = ['y', 'z'];
+        let s2 = s1
+            .with_req_field1_ref(&chars[0])
+            .with_opt_field1_ref(&chars[1])
+            .with_def_field1('B');
+        assert_eq!(s2.req_field1, 'y');
+        assert_eq!(s2.opt_field1, Some('z'));
+        assert_eq!(s2.def_field1, 'B');
+    }
+
+    #[test]
+    fn option_or_setters() {
+        let s1 = SimpleStrValueStruct::new("hey".into(), 0)
+            .with_opt_field1("hey2".into())
+            .with_opt_field1_or("hey3".into())
+            .with_opt_field2_or(10);
+        assert_eq!(s1.opt_field1, Some("hey2".into()));
+        assert_eq!(s1.opt_field2, Some(10));
+    }
+
+    fn build_generic_value_struct<T: Copy + Clone>(x: T) -> GenericValueStructWithBounds<T> {
+        let mut s = GenericValueStructWithBounds::new(x)
+            .with_opt_gen_field1(x)
+            .with_opt_gen_field2("hey".into());
+        s.gen_field1(x).reset_opt_gen_field2();
+        s
+    }
+
+    trait GenericValueStructFactory<T: Copy + Clone> {
+        fn value(&self) -> T;
+
+        fn build(&self) -> GenericValueStructWithBoundsWhere<T> {
+            GenericValueStructWithBoundsWhere::new(self.value()).with_opt_gen_field1(self.value())
+        }
+    }
+
+    impl GenericValueStructFactory<i64> for i64 {
+        fn value(&self) -> i64 {
+            *self
+        }
+    }
+
+    #[test]
+    fn generic_struct_with_bounds_in_generic_fn() {
+        let s1 = build_generic_value_struct(17);
+        assert_eq!(s1.gen_field1, 17);
+        assert_eq!(s1.opt_gen_field1, Some(17));
+        assert_eq!(s1.opt_gen_field2, None);
+
+        let s2 = 37i64.build();
+        assert_eq!(s2.gen_field1, 37);
+        assert_eq!(s2.opt_gen_field1, Some(37));
+    }
+
+    #[test]
+    fn struct_with_clone_setters() {
+        let base = StructWithCloneSetters::new("hey".into());
+        let s1 = base.with_opt_field1(1);
+        let s2 = base.with_opt_field1(2).with_verbose();
+        let s3 = base
+            .with_req_field1("hey2".into())
+            .with_verbose_value(false);
+
+        assert_eq!(base.opt_field1, None);
+        assert_eq!(s1.opt_field1, Some(1));
+        assert_eq!(s2.opt_field1, Some(2));
+        assert!(s2.verbose);
+        assert_eq!(s3.req_field1, "hey2");
+        assert_eq!(base.req_field1, "hey");
+    }
+
+    #[test]
+    fn struct_with_trace() {
+        log::set_logger(&TRACE_LOGGER).unwrap();
+        log::set_max_level(log::LevelFilter::Debug);
+
+        let mut s1 = StructWithTrace::new("hey".into(), vec![]);
+        s1.req_field1("hey2".into())
+            .opt_field1(1)
+            .push_vec_field1(2)
+            .reset_opt_field1();
+
+        assert_eq!(
+            *TRACE_LOGGER.lines.lock().unwrap(),
+            vec![
+                "req_field1 = \"hey2\"",
+                "opt_field1 = Some(1)",
+                "vec_field1 = [2]",
+                "opt_field1 = None",
+            ]
+        );
+    }
+
+    #[test]
+    fn struct_with_shadowed_field_defaults() {
+        let s1 = StructWithShadowedFieldDefaults::new(1);
+        assert_eq!(s1.i, 1);
+        assert_eq!(s1.sum_field, 6);
+        assert_eq!(s1.block_field, 6);
+        assert_eq!(s1.name, None);
+        assert_eq!(s1.match_field, 1);
+        assert_eq!(s1.if_let_field, 4);
     }
 }
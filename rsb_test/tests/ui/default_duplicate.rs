@@ -0,0 +1,10 @@
+use rsb_derive::Builder;
+
+#[derive(Builder)]
+struct StructWithDuplicateDefault {
+    #[default = "1"]
+    #[default = "2"]
+    field: i32,
+}
+
+fn main() {}
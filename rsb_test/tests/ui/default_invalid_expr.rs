@@ -0,0 +1,10 @@
+use rsb_derive::Builder;
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+struct StructWithInvalidDefaultExpr {
+    pub req_field1: String,
+    #[default = "1 +"]
+    pub req_field2: i32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use rsb_derive::Builder;
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+struct StructWithInvalidDefault {
+    pub req_field1: String,
+    #[default = 10]
+    pub req_field2: i32,
+}
+
+fn main() {}
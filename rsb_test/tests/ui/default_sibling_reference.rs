@@ -0,0 +1,10 @@
+use rsb_derive::Builder;
+
+#[derive(Builder)]
+struct StructWithSiblingDefault {
+    pub req_field1: String,
+    #[default = "req_field1.clone()"]
+    pub def_field1: String,
+}
+
+fn main() {}
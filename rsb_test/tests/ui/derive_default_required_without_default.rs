@@ -0,0 +1,13 @@
+use rsb_derive::Builder;
+
+#[derive(Debug, Clone, PartialEq)]
+struct NoDefault(i32);
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+#[builder(derive_default)]
+struct StructWithDerivedDefault {
+    pub req_field1: String,
+    pub req_field2: NoDefault,
+}
+
+fn main() {}
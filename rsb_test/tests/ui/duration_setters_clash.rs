@@ -0,0 +1,10 @@
+use rsb_derive::Builder;
+use std::time::Duration;
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+struct StructWithClashingDurationSetters {
+    pub timeout: Duration,
+    pub timeout_secs: Option<u64>,
+}
+
+fn main() {}
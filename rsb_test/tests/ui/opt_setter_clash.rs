@@ -0,0 +1,15 @@
+use rsb_derive::Builder;
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+struct StructWithClashingOptSetters {
+    pub name: String,
+    pub name_opt: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+struct StructWithClashingIfSetters {
+    pub value: u32,
+    pub value_if: Option<u32>,
+}
+
+fn main() {}
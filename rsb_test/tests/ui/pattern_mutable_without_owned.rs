@@ -0,0 +1,11 @@
+use rsb_derive::Builder;
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+#[builder(pattern = "mutable")]
+struct StructWithMutablePattern {
+    pub req_field1: String,
+}
+
+fn main() {
+    let _ = StructWithMutablePattern::new("hey".into()).with_req_field1("hey2".into());
+}
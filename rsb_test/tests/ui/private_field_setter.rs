@@ -0,0 +1,13 @@
+mod restricted {
+    use rsb_derive::Builder;
+
+    #[derive(Debug, Clone, PartialEq, Builder)]
+    pub struct StructWithPrivateField {
+        pub req_field1: String,
+        req_field2: i32,
+    }
+}
+
+fn main() {
+    let _ = restricted::StructWithPrivateField::new("hey".into(), 1).with_req_field2(2);
+}
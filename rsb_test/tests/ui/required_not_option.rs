@@ -0,0 +1,9 @@
+use rsb_derive::Builder;
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+struct StructWithInvalidRequired {
+    #[builder(required)]
+    pub req_field1: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use rsb_derive::Builder;
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+struct StructWithSkipSetters {
+    #[builder(skip_setters)]
+    pub id: u64,
+    pub req_field1: String,
+}
+
+fn main() {
+    let _ = StructWithSkipSetters::new(1, "hey".into()).with_id(2);
+}
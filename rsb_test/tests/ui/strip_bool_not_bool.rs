@@ -0,0 +1,9 @@
+use rsb_derive::Builder;
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+struct StructWithFlags {
+    #[builder(setter(strip_bool))]
+    pub req_field1: String,
+}
+
+fn main() {}
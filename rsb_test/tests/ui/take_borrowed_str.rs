@@ -0,0 +1,11 @@
+use rsb_derive::Builder;
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+struct StructWithStrSlice<'a> {
+    pub req_field1: &'a str,
+}
+
+fn main() {
+    let mut s = StructWithStrSlice::new("hey");
+    s.take_req_field1();
+}
@@ -0,0 +1,9 @@
+use rsb_derive::Builder;
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+struct TupleStructWithUnknownAttr(#[builder(totally_unknown)] pub u32, pub Option<String>);
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+struct TupleStructWithFieldAttr(#[builder(into)] pub String, pub Option<String>);
+
+fn main() {}
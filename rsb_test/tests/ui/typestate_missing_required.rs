@@ -0,0 +1,16 @@
+use rsb_derive::Builder;
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+#[builder(typestate)]
+struct StructWithTypestate {
+    pub req_field1: String,
+    pub req_field2: i32,
+    pub opt_field1: Option<String>,
+}
+
+fn main() {
+    let _ = StructWithTypestate::typestate_builder()
+        .with_req_field1("hey".into())
+        .with_opt_field1("hey2".into())
+        .build();
+}
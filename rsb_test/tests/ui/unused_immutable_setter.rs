@@ -0,0 +1,14 @@
+#![deny(unused_must_use)]
+
+use rsb_derive::Builder;
+
+#[derive(Debug, Clone, PartialEq, Builder)]
+struct SimpleStruct {
+    pub req_field1: String,
+    pub opt_field1: Option<i32>,
+}
+
+fn main() {
+    let s = SimpleStruct::new("hey".into());
+    s.with_opt_field1(10);
+}